@@ -1,338 +1,1421 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 
-use crate::case::Case;
+use crate::case::{self, Case};
 use proc_macro2::{Ident, TokenStream};
-use syn::{DeriveInput, Meta};
+use quote::ToTokens;
+use syn::{meta::ParseNestedMeta, token, DeriveInput, LitStr, Token};
 
 /// The attribute name used for enum variant renaming.
 static ATTRIBUTE_NAME: &str = "enum_stringify";
 
-/// Parses a string literal by removing surrounding double quotes if present.
-///
-/// # Arguments
-/// * `s` - A string slice containing the quoted string.
-///
-/// # Returns
-/// * `Ok(String)` if the string is correctly formatted.
-/// * `Err(&'static str)` if the string is not enclosed in double quotes.
-fn parse_string(s: &str) -> Result<String, &'static str> {
-    s.strip_prefix('"')
-        .and_then(|s| s.strip_suffix('"'))
-        .map(std::string::ToString::to_string)
-        .ok_or("String must be enclosed in double quotes")
+/// Keys accepted in the attribute placed on the enum.
+const ENUM_KEYS: &[&str] = &[
+    "prefix",
+    "suffix",
+    "case",
+    "alternate_case",
+    "parse_case_insensitive",
+    "literal_macro",
+    "impls",
+    "table",
+];
+
+/// Keys accepted in an attribute placed on the enum that defines a table.
+const TABLE_KEYS: &[&str] = &["table", "prefix", "suffix", "case"];
+
+/// Keys accepted in an attribute placed on a variant that refers to a table.
+const VARIANT_TABLE_KEYS: &[&str] = &["table", "rename"];
+
+/// Items generated by the derive, which the methods named after tables and
+/// meta keys must not clash with. The methods of the generated traits are
+/// included, as an inherent method would shadow them; the methods of the
+/// common derives are in `DERIVED_METHODS`.
+const GENERATED_ITEMS: &[&str] = &[
+    "VARIANTS",
+    "NAMES",
+    "DESCRIPTIONS",
+    "COUNT",
+    "GROUPS",
+    "fmt",
+    "to_string",
+    "try_from",
+    "as_str",
+    "description",
+    "iter",
+    "from_str",
+    "from_str_const",
+    "parse_borrowed",
+    "get_meta",
+    "group",
+    "in_group",
+    "group_variants",
+    "group_names",
+    "from_str_in_group",
+];
+
+/// Methods of the traits commonly derived next to this one, which a meta key
+/// accessor would shadow.
+const DERIVED_METHODS: &[&str] = &["clone", "eq", "ne", "cmp", "partial_cmp", "hash", "default"];
+
+/// Trait implementations and inherent items that can be selected with
+/// `impls(...)`.
+const IMPL_KEYS: &[&str] = &[
+    "display",
+    "from_str",
+    "try_from_str",
+    "try_from_string",
+    "as_str",
+    "from_str_const",
+    "constants",
+    "variants",
+    "description",
+];
+
+/// Keys accepted in the attribute placed on a variant.
+const VARIANT_KEYS: &[&str] = &[
+    "rename",
+    "to",
+    "from",
+    "alias",
+    "alternate",
+    "fields",
+    "fallback",
+    "other",
+    "skip",
+    "skip_display",
+    "skip_parse",
+    "table",
+    "meta",
+    "group",
+    "case",
+    "prefix",
+    "suffix",
+    "no_prefix",
+    "no_suffix",
+];
+
+/// Parses the string literal value of a `key = "value"` argument.
+fn parse_string(meta: &ParseNestedMeta) -> syn::Result<String> {
+    Ok(meta.value()?.parse::<LitStr>()?.value())
+}
+
+/// Computes the Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Returns the candidate closest to `s`, if it is close enough to be a typo.
+pub(crate) fn closest<'a>(s: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(s, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= s.len().max(3) / 3)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Parses the value of a `key = "value"` argument that must be an identifier,
+/// describing it as `what` in the error.
+fn parse_ident(meta: &ParseNestedMeta, what: &str) -> syn::Result<Ident> {
+    let value = meta.value()?.parse::<LitStr>()?;
+    value.parse::<Ident>().map_err(|_| {
+        syn::Error::new_spanned(&value, format!("`{}` is not a valid {what}", value.value()))
+    })
+}
+
+/// A value given to a variant with `meta(key = value)`.
+#[derive(Clone, Debug)]
+pub(crate) struct MetaValue {
+    pub(crate) key: Ident,
+    /// The type of the value, from the kind of literal and its suffix.
+    pub(crate) ty: String,
+    /// The value as returned by `get_meta`.
+    pub(crate) string: String,
+    /// The literal as written.
+    pub(crate) literal: TokenStream,
+}
+
+impl PartialEq for MetaValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+            && self.ty == other.ty
+            && self.literal.to_string() == other.literal.to_string()
+    }
+}
+
+impl MetaValue {
+    /// Parses a `key = value` argument of `meta(...)`.
+    fn parse(meta: &ParseNestedMeta) -> syn::Result<Self> {
+        let key = meta.path.require_ident()?.clone();
+        // Keywords are accepted as keys, but can not name a method.
+        if syn::parse_str::<Ident>(&key.to_string()).is_err() {
+            return Err(syn::Error::new(
+                key.span(),
+                format!("`{key}` is a keyword and can not be used as a meta key"),
+            ));
+        }
+        let value = meta.value()?.parse::<syn::Lit>()?;
+        let (ty, string) = match &value {
+            syn::Lit::Str(value) => ("&'static str".to_string(), value.value()),
+            syn::Lit::Int(value) => {
+                let ty = if value.suffix().is_empty() {
+                    "i64"
+                } else {
+                    value.suffix()
+                };
+                (ty.to_string(), value.base10_digits().to_string())
+            }
+            syn::Lit::Float(value) => {
+                let ty = if value.suffix().is_empty() {
+                    "f64"
+                } else {
+                    value.suffix()
+                };
+                (ty.to_string(), value.base10_digits().to_string())
+            }
+            syn::Lit::Bool(value) => ("bool".to_string(), value.value.to_string()),
+            syn::Lit::Char(value) => ("char".to_string(), value.value().to_string()),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    value,
+                    "`meta` values must be strings, numbers, booleans or characters",
+                ))
+            }
+        };
+        Ok(Self {
+            key,
+            ty,
+            string,
+            literal: value.to_token_stream(),
+        })
+    }
 }
 
-/// Parses a list of attribute tokens into a vector of type `T`.
+/// Returns the text of the doc comments among `attributes`.
 ///
-/// # Arguments
-/// * `tokens` - A reference to a token stream containing attributes.
+/// Each line is trimmed, so that the indentation of the comments is not part
+/// of the text, and lines are joined with `\n`.
+fn parse_doc(attributes: &[syn::Attribute]) -> String {
+    let lines: Vec<String> = attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("doc"))
+        .filter_map(|attribute| match &attribute.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(doc),
+                        ..
+                    }),
+                ..
+            }) => Some(doc.value()),
+            _ => None,
+        })
+        .flat_map(|doc| {
+            doc.split('\n')
+                .map(|line| line.trim().to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    lines.join("\n").trim().to_string()
+}
+
+/// Returns the table an attribute refers to, as in `table = "short"`.
 ///
-/// # Returns
-/// * `Ok(Vec<T>)` if parsing succeeds.
-/// * `Err(String)` if parsing fails due to incorrect syntax.
-fn parse_token_list<T>(tokens: &TokenStream) -> Result<Vec<T>, String>
-where
-    T: TryFrom<(String, String)>,
-{
-    let mut result = Vec::new();
-    let mut tokens = tokens.clone().into_iter();
+/// The other arguments are skipped, they are parsed once it is known whether
+/// they apply to a table.
+fn find_table(attribute: &syn::Attribute) -> syn::Result<Option<Ident>> {
+    let mut table = None;
+    attribute.parse_nested_meta(|meta| {
+        if meta.path.is_ident("table") {
+            table = Some(parse_ident(&meta, "table name")?);
+        } else {
+            while !meta.input.is_empty() && !meta.input.peek(Token![,]) {
+                meta.input.parse::<proc_macro2::TokenTree>()?;
+            }
+        }
+        Ok(())
+    })?;
+    Ok(table)
+}
+
+/// Checks that the `as_{table}` and `from_{table}` methods of a table are in
+/// snake case, and do not clash with the other generated methods.
+fn check_table_name(name: &Ident) -> syn::Result<()> {
+    let table = name.to_string();
+    if table.chars().any(char::is_uppercase) || table.starts_with('_') || table.contains("__") {
+        return Err(syn::Error::new(
+            name.span(),
+            format!("table name `{name}` is not in snake case, like `short` or `api_code`"),
+        ));
+    }
+    for method in [format!("as_{name}"), format!("from_{name}")] {
+        if GENERATED_ITEMS.contains(&method.as_str()) {
+            return Err(syn::Error::new(
+                name.span(),
+                format!("table name `{name}` clashes with the generated method `{method}`"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Builds the error reported for a key that is not accepted in an attribute
+/// referring to a table.
+fn table_key_error(meta: &ParseNestedMeta, keys: &[&str], table_keys: &[&str]) -> syn::Error {
+    match meta.path.get_ident() {
+        Some(key) if keys.iter().any(|known| key == known) => {
+            meta.error(format!("`{key}` can not be used together with `table`"))
+        }
+        _ => unknown_key(meta, table_keys),
+    }
+}
+
+/// Builds the error reported for an unknown key, suggesting the closest known key.
+fn unknown_key(meta: &ParseNestedMeta, known: &[&str]) -> syn::Error {
+    let key = meta
+        .path
+        .get_ident()
+        .map(ToString::to_string)
+        .unwrap_or_default();
+    let mut message = format!("unknown {ATTRIBUTE_NAME} attribute `{key}`");
+    if let Some(suggestion) = closest(&key, known) {
+        message.push_str(&format!(", did you mean `{suggestion}`?"));
+    } else {
+        message.push_str(&format!(", expected one of: {}", known.join(", ")));
+    }
+    meta.error(message)
+}
+
+/// Adds `error` to the errors collected so far.
+fn combine_error(errors: &mut Option<syn::Error>, error: syn::Error) {
+    match errors {
+        Some(errors) => errors.combine(error),
+        None => *errors = Some(error),
+    }
+}
+
+/// Which unrecognised strings are parsed into the fallback variant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Fallback {
+    /// Any unrecognised string except the empty one.
+    NonEmpty,
+    /// Any unrecognised string, including the empty one.
+    Any,
+}
+
+impl Fallback {
+    /// Parses a `fallback` argument, optionally followed by `(empty)`.
+    fn parse(meta: &ParseNestedMeta) -> syn::Result<Self> {
+        if !meta.input.peek(token::Paren) {
+            return Ok(Self::NonEmpty);
+        }
+        meta.parse_nested_meta(|meta| {
+            if meta.path.is_ident("empty") {
+                Ok(())
+            } else {
+                Err(unknown_key(&meta, &["empty"]))
+            }
+        })?;
+        Ok(Self::Any)
+    }
+}
+
+/// The case, prefix and suffix given on a variant, taking precedence over
+/// those of the enum.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Overrides {
+    case: Option<Case>,
+    prefix: Option<String>,
+    suffix: Option<String>,
+    no_prefix: bool,
+    no_suffix: bool,
+}
+
+impl Overrides {
+    /// Returns the first of the override keys that is given, if any.
+    fn key(&self) -> Option<&'static str> {
+        if self.case.is_some() {
+            Some("case")
+        } else if self.prefix.is_some() {
+            Some("prefix")
+        } else if self.suffix.is_some() {
+            Some("suffix")
+        } else if self.no_prefix {
+            Some("no_prefix")
+        } else if self.no_suffix {
+            Some("no_suffix")
+        } else {
+            None
+        }
+    }
+
+    /// Returns the prefix used for the variant, given the one of the enum.
+    fn prefix<'a>(&'a self, prefix: Option<&'a str>) -> Option<&'a str> {
+        if self.no_prefix {
+            None
+        } else {
+            self.prefix.as_deref().or(prefix)
+        }
+    }
+
+    /// Returns the suffix used for the variant, given the one of the enum.
+    fn suffix<'a>(&'a self, suffix: Option<&'a str>) -> Option<&'a str> {
+        if self.no_suffix {
+            None
+        } else {
+            self.suffix.as_deref().or(suffix)
+        }
+    }
+}
+
+/// Represents the attributes that can be set on an enum variant.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct VariantAttributes {
+    rename: Option<String>,
+    to: Option<String>,
+    from: Vec<String>,
+    aliases: Vec<String>,
+    alternate: Option<String>,
+    /// Strings used for the variant in the tables, instead of its name.
+    table_renames: Vec<(Ident, String)>,
+    /// The doc comments of the variant.
+    description: String,
+    /// Values given with `meta(key = value)`, in the order they are written.
+    meta: Vec<MetaValue>,
+    group: Option<String>,
+    overrides: Overrides,
+    default_fields: bool,
+    fallback: Option<Fallback>,
+    other: bool,
+    skip_display: bool,
+    skip_parse: bool,
+    placeholder: Option<String>,
+}
+
+impl VariantAttributes {
+    /// Parses the attributes of a given enum variant.
+    fn new(variant: &syn::Variant) -> syn::Result<Self> {
+        let mut new = Self {
+            description: parse_doc(&variant.attrs),
+            ..Self::default()
+        };
+        for attribute in &variant.attrs {
+            new.parse_args(attribute, &variant.fields)?;
+        }
+
+        if new.other {
+            return new.check_other(variant).map(|()| new);
+        }
 
-    while let Some(attribute_type) = tokens.next() {
-        let attribute_type = attribute_type.to_string();
+        if let (Some(_), Some(key)) = (&new.rename, new.overrides.key()) {
+            return Err(syn::Error::new_spanned(
+                &variant.ident,
+                format!("`{key}` can not be used together with `rename`"),
+            ));
+        }
 
-        let Some(eq_token) = tokens.next() else {
-            return Err(format!("Expected '=' after '{attribute_type}'"));
+        let conflict = if new.rename.is_some() && new.to.is_some() {
+            Some("`rename` can not be used together with `to`, use `from` instead")
+        } else if new.rename.is_some() && !new.from.is_empty() {
+            Some("`rename` can not be used together with `from`, use `to` instead")
+        } else if new.skip_display && new.to.is_some() {
+            Some("`to` can not be used on a variant skipped from Display")
+        } else if new.skip_display && new.alternate.is_some() {
+            Some("`alternate` can not be used on a variant skipped from Display")
+        } else if new.skip_parse && !new.from.is_empty() {
+            Some("`from` can not be used on a variant skipped when parsing")
+        } else if new.skip_parse && !new.aliases.is_empty() {
+            Some("`alias` can not be used on a variant skipped when parsing")
+        } else if new.skip_parse && new.fallback.is_some() {
+            Some("`fallback` can not be used on a variant skipped when parsing")
+        } else if new.overrides.prefix.is_some() && new.overrides.no_prefix {
+            Some("`prefix` can not be used together with `no_prefix`")
+        } else if new.overrides.suffix.is_some() && new.overrides.no_suffix {
+            Some("`suffix` can not be used together with `no_suffix`")
+        } else {
+            None
         };
-        if eq_token.to_string() != "=" {
-            return Err(format!("Unexpected token '{eq_token}', expected '='"));
+        if let Some(conflict) = conflict {
+            return Err(syn::Error::new_spanned(&variant.ident, conflict));
+        }
+
+        let parsed = matches!(variant.fields, syn::Fields::Unit) || new.default_fields;
+        if !parsed {
+            let key = if !new.aliases.is_empty() {
+                "alias"
+            } else if !new.from.is_empty() {
+                "from"
+            } else if new.fallback.is_some() {
+                "fallback"
+            } else {
+                return Ok(new);
+            };
+            return Err(syn::Error::new_spanned(
+                &variant.ident,
+                format!(
+                    "`{key}` can only be used on variants that are parsed, \
+                     add `fields = \"default\"` to parse this variant"
+                ),
+            ));
         }
+        Ok(new)
+    }
 
-        let value = tokens.next().ok_or("Value must be specified")?.to_string();
+    /// Checks that a variant marked with `other` can hold the unrecognised
+    /// strings, and is not given a name that it would never use.
+    fn check_other(&self, variant: &syn::Variant) -> syn::Result<()> {
+        if !matches!(&variant.fields, syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1) {
+            return Err(syn::Error::new_spanned(
+                &variant.ident,
+                "`other` can only be used on a variant with a single unnamed field, like `Other(String)`",
+            ));
+        }
+        let key = if self.rename.is_some() {
+            "rename"
+        } else if self.to.is_some() {
+            "to"
+        } else if !self.from.is_empty() {
+            "from"
+        } else if !self.aliases.is_empty() {
+            "alias"
+        } else if self.alternate.is_some() {
+            "alternate"
+        } else if !self.table_renames.is_empty() {
+            "table"
+        } else if let Some(key) = self.overrides.key() {
+            key
+        } else if self.default_fields {
+            "fields"
+        } else if self.fallback.is_some() {
+            "fallback"
+        } else if self.skip_display || self.skip_parse {
+            "skip"
+        } else {
+            return Ok(());
+        };
+        Err(syn::Error::new_spanned(
+            &variant.ident,
+            format!("`{key}` can not be used together with `other`"),
+        ))
+    }
 
-        match T::try_from((attribute_type.clone(), value)) {
-            Ok(value) => result.push(value),
-            Err(_) => return Err(format!("Invalid argument: {attribute_type}")),
+    /// Parses the variant attributes from a given `syn::Attribute`, ignoring
+    /// attributes that do not belong to this crate.
+    fn parse_args(&mut self, attribute: &syn::Attribute, fields: &syn::Fields) -> syn::Result<()> {
+        if !attribute.path().is_ident(ATTRIBUTE_NAME) {
+            return Ok(());
+        }
+        let Some(table) = find_table(attribute)? else {
+            return attribute.parse_nested_meta(|meta| self.update_attribute(&meta, fields));
+        };
+        let mut rename = None;
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("table") {
+                parse_string(&meta)?;
+            } else if meta.path.is_ident("rename") {
+                rename = Some(parse_string(&meta)?);
+            } else {
+                return Err(table_key_error(&meta, VARIANT_KEYS, VARIANT_TABLE_KEYS));
+            }
+            Ok(())
+        })?;
+        if let Some(rename) = rename {
+            self.table_renames.push((table, rename));
         }
+        Ok(())
+    }
 
-        if let Some(comma_separator) = tokens.next() {
-            if comma_separator.to_string() != "," {
-                return Err("Expected a comma-separated attribute list".to_string());
+    fn update_attribute(
+        &mut self,
+        meta: &ParseNestedMeta,
+        fields: &syn::Fields,
+    ) -> syn::Result<()> {
+        if meta.path.is_ident("rename") {
+            self.rename = Some(parse_string(meta)?);
+        } else if meta.path.is_ident("to") {
+            self.to = Some(parse_string(meta)?);
+        } else if meta.path.is_ident("from") {
+            self.from.push(parse_string(meta)?);
+        } else if meta.path.is_ident("alternate") {
+            self.alternate = Some(parse_string(meta)?);
+        } else if meta.path.is_ident("alias") {
+            self.aliases.push(parse_string(meta)?);
+        } else if meta.path.is_ident("meta") {
+            meta.parse_nested_meta(|meta| {
+                let value = MetaValue::parse(&meta)?;
+                if self.meta.iter().any(|known| known.key == value.key) {
+                    return Err(meta.error(format!("duplicate meta key `{}`", value.key)));
+                }
+                self.meta.push(value);
+                Ok(())
+            })?;
+        } else if meta.path.is_ident("group") {
+            if self.group.is_some() {
+                return Err(meta.error("duplicate `group`, a variant belongs to at most one group"));
             }
+            self.group = Some(parse_string(meta)?);
+        } else if meta.path.is_ident("case") {
+            self.overrides.case = Some(Case::parse(meta)?);
+        } else if meta.path.is_ident("prefix") {
+            self.overrides.prefix = Some(parse_string(meta)?);
+        } else if meta.path.is_ident("suffix") {
+            self.overrides.suffix = Some(parse_string(meta)?);
+        } else if meta.path.is_ident("no_prefix") {
+            self.overrides.no_prefix = true;
+        } else if meta.path.is_ident("no_suffix") {
+            self.overrides.no_suffix = true;
+        } else if meta.path.is_ident("fallback") {
+            self.fallback = Some(Fallback::parse(meta)?);
+        } else if meta.path.is_ident("other") {
+            self.other = true;
+        } else if meta.path.is_ident("skip") {
+            self.skip_display = true;
+            self.skip_parse = true;
+            self.parse_placeholder(meta)?;
+        } else if meta.path.is_ident("skip_display") {
+            self.skip_display = true;
+            self.parse_placeholder(meta)?;
+        } else if meta.path.is_ident("skip_parse") {
+            self.skip_parse = true;
+        } else if meta.path.is_ident("fields") {
+            let value = meta.value()?.parse::<LitStr>()?;
+            if value.value() != "default" {
+                return Err(syn::Error::new_spanned(
+                    value,
+                    "the only supported value for `fields` is \"default\"",
+                ));
+            }
+            if matches!(fields, syn::Fields::Unit) {
+                return Err(meta.error("`fields` can only be used on tuple and struct variants"));
+            }
+            self.default_fields = true;
+        } else {
+            return Err(unknown_key(meta, VARIANT_KEYS));
+        }
+        Ok(())
+    }
+
+    /// Parses the optional placeholder displayed instead of a variant
+    /// skipped from `Display`, as in `skip_display = "..."`.
+    fn parse_placeholder(&mut self, meta: &ParseNestedMeta) -> syn::Result<()> {
+        if meta.input.peek(Token![=]) {
+            self.placeholder = Some(parse_string(meta)?);
         }
+        Ok(())
     }
-    Ok(result)
 }
 
-/// Represents a rename attribute for an enum variant.
-#[derive(Clone, Debug, PartialEq)]
-struct VariantRename(String);
+/// How an input string is compared to the variant names when parsing.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub(crate) enum Matching {
+    /// The input must be exactly the name of the variant.
+    #[default]
+    Exact,
+    /// The input may differ from the name in ASCII casing.
+    AsciiCaseInsensitive,
+    /// The input may differ from the name in Unicode casing.
+    UnicodeCaseInsensitive,
+}
 
-impl TryFrom<(String, String)> for VariantRename {
-    type Error = &'static str;
+impl Matching {
+    /// Parses a `parse_case_insensitive` argument, which defaults to ASCII
+    /// when no value is given.
+    fn parse(meta: &ParseNestedMeta) -> syn::Result<Self> {
+        if !meta.input.peek(Token![=]) {
+            return Ok(Self::AsciiCaseInsensitive);
+        }
+        let value = meta.value()?.parse::<LitStr>()?;
+        match value.value().as_str() {
+            "ascii" => Ok(Self::AsciiCaseInsensitive),
+            "unicode" => Ok(Self::UnicodeCaseInsensitive),
+            _ => Err(syn::Error::new_spanned(
+                value,
+                "expected \"ascii\" or \"unicode\"",
+            )),
+        }
+    }
 
-    fn try_from(value: (String, String)) -> Result<Self, Self::Error> {
-        if value.0 == "rename" {
-            Ok(Self(parse_string(&value.1)?))
-        } else {
-            Err("Not a rename string")
+    /// Returns the form of `s` under which two strings are considered equal.
+    pub(crate) fn fold<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        match self {
+            Self::Exact => Cow::Borrowed(s),
+            Self::AsciiCaseInsensitive => Cow::Owned(s.to_ascii_lowercase()),
+            Self::UnicodeCaseInsensitive => {
+                Cow::Owned(s.chars().flat_map(char::to_lowercase).collect())
+            }
         }
     }
 }
 
-impl VariantRename {
-    /// Parses the rename attribute from a given `syn::Attribute`.
-    fn parse_args(attribute: &syn::Attribute) -> Option<Self> {
-        if !attribute.path().is_ident(ATTRIBUTE_NAME) {
-            return None;
+/// The trait implementations and inherent items generated by the derive,
+/// all of them unless some are selected with `impls(...)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Impls {
+    pub(crate) display: bool,
+    pub(crate) from_str: bool,
+    pub(crate) try_from_str: bool,
+    pub(crate) try_from_string: bool,
+    /// The inherent `as_str`.
+    pub(crate) as_str: bool,
+    /// The inherent `from_str_const`.
+    pub(crate) from_str_const: bool,
+    /// The `_STR` constant of each variant.
+    pub(crate) constants: bool,
+    /// `VARIANTS`, `NAMES`, `COUNT` and `iter`.
+    pub(crate) variants: bool,
+    /// The inherent `description` and `DESCRIPTIONS`.
+    pub(crate) description: bool,
+}
+
+impl Default for Impls {
+    fn default() -> Self {
+        Self {
+            display: true,
+            from_str: true,
+            try_from_str: true,
+            try_from_string: true,
+            as_str: true,
+            from_str_const: true,
+            constants: true,
+            variants: true,
+            description: true,
         }
+    }
+}
 
-        match &attribute.meta {
-            Meta::List(list) => parse_token_list::<Self>(&list.tokens)
-                .ok()?
-                .first()
-                .cloned(),
-            _ => None,
+impl Impls {
+    /// Nothing is generated.
+    const NONE: Self = Self {
+        display: false,
+        from_str: false,
+        try_from_str: false,
+        try_from_string: false,
+        as_str: false,
+        from_str_const: false,
+        constants: false,
+        variants: false,
+        description: false,
+    };
+
+    /// Parses an `impls(...)` argument, enabling only the listed items. An
+    /// empty list, `impls()`, enables none of them.
+    fn parse(meta: &ParseNestedMeta) -> syn::Result<Self> {
+        let mut new = Self::NONE;
+        let content;
+        syn::parenthesized!(content in meta.input);
+        let list: TokenStream = content.parse()?;
+        if list.is_empty() {
+            return Ok(new);
         }
+        let parser = syn::meta::parser(|meta| {
+            if meta.path.is_ident("display") {
+                new.display = true;
+            } else if meta.path.is_ident("from_str") {
+                new.from_str = true;
+            } else if meta.path.is_ident("try_from_str") {
+                new.try_from_str = true;
+            } else if meta.path.is_ident("try_from_string") {
+                new.try_from_string = true;
+            } else if meta.path.is_ident("as_str") {
+                new.as_str = true;
+            } else if meta.path.is_ident("from_str_const") {
+                new.from_str_const = true;
+            } else if meta.path.is_ident("constants") {
+                new.constants = true;
+            } else if meta.path.is_ident("variants") {
+                new.variants = true;
+            } else if meta.path.is_ident("description") {
+                new.description = true;
+            } else {
+                return Err(unknown_key(&meta, IMPL_KEYS));
+            }
+            Ok(())
+        });
+        syn::parse::Parser::parse2(parser, list)?;
+        Ok(new)
+    }
+
+    /// Returns whether any of the parsing traits is implemented, which need
+    /// the parse error type.
+    pub(crate) fn parse_any(&self) -> bool {
+        self.from_str || self.try_from_str || self.try_from_string
     }
 }
 
+/// A named set of strings for the variants, converted with their own prefix,
+/// suffix and case, as in `#[enum_stringify(table = "short", case = "upper")]`.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Table {
+    pub(crate) name: Ident,
+    attributes: Attributes,
+}
+
 /// Represents attribute configurations for renaming enum variants.
 #[derive(Default, Debug, PartialEq, Eq)]
 pub struct Attributes {
     case: Option<Case>,
+    alternate_case: Option<Case>,
     prefix: Option<String>,
     suffix: Option<String>,
+    /// How strings are compared to the variant names when parsing.
+    pub(crate) matching: Matching,
+    /// Name of the macro turning string literals into variants, if any.
+    pub(crate) literal_macro: Option<Ident>,
+    /// The trait implementations to generate.
+    pub(crate) impls: Impls,
+    /// Additional sets of strings, in the order they are declared.
+    pub(crate) tables: Vec<Table>,
 }
 
 impl Attributes {
     /// Constructs an `Attributes` instance by parsing the attributes of a derive input.
-    pub(crate) fn new(ast: &DeriveInput) -> Self {
+    pub(crate) fn new(ast: &DeriveInput) -> syn::Result<Self> {
         let mut new = Self::default();
-        ast.attrs.iter().for_each(|attr| {
-            if let Some(rename_rules) = Self::parse_args(attr) {
-                new.prefix = rename_rules.prefix;
-                new.suffix = rename_rules.suffix;
-                new.case = rename_rules.case;
-            }
-        });
-        new
+        for attribute in &ast.attrs {
+            new.parse_args(attribute)?;
+        }
+        Ok(new)
     }
 
-    fn parse_args(attribute: &syn::Attribute) -> Option<Self> {
+    /// Parses the enum attributes from a given `syn::Attribute`, ignoring
+    /// attributes that do not belong to this crate.
+    fn parse_args(&mut self, attribute: &syn::Attribute) -> syn::Result<()> {
         if !attribute.path().is_ident(ATTRIBUTE_NAME) {
-            return None;
+            return Ok(());
         }
+        let Some(name) = find_table(attribute)? else {
+            return attribute.parse_nested_meta(|meta| self.update_attribute(&meta));
+        };
 
-        let mut new = Self::default();
-        match &attribute.meta {
-            Meta::List(list) => {
-                let attributes = parse_token_list::<(String, String)>(&list.tokens).ok()?;
-                for value in attributes {
-                    new.update_attribute(value);
-                }
-                Some(new)
+        // Attributes naming the same table add to it.
+        let index = match self.tables.iter().position(|table| table.name == name) {
+            Some(index) => index,
+            None => {
+                check_table_name(&name)?;
+                self.tables.push(Table {
+                    name,
+                    attributes: Attributes::default(),
+                });
+                self.tables.len() - 1
             }
-            _ => None,
-        }
+        };
+        let table = &mut self.tables[index].attributes;
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("table") {
+                parse_string(&meta)?;
+                Ok(())
+            } else if TABLE_KEYS.iter().any(|key| meta.path.is_ident(key)) {
+                table.update_attribute(&meta)
+            } else {
+                Err(table_key_error(&meta, ENUM_KEYS, TABLE_KEYS))
+            }
+        })
     }
 
-    fn update_attribute(&mut self, value: (String, String)) {
-        match value.0.as_str() {
-            "prefix" => self.prefix = parse_string(&value.1).ok(),
-            "suffix" => self.suffix = parse_string(&value.1).ok(),
-            "case" => self.case = Case::try_from(value).ok(),
-            _ => {}
+    fn update_attribute(&mut self, meta: &ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("prefix") {
+            self.prefix = Some(parse_string(meta)?);
+        } else if meta.path.is_ident("suffix") {
+            self.suffix = Some(parse_string(meta)?);
+        } else if meta.path.is_ident("case") {
+            self.case = Some(Case::parse(meta)?);
+        } else if meta.path.is_ident("alternate_case") {
+            self.alternate_case = Some(Case::parse(meta)?);
+        } else if meta.path.is_ident("parse_case_insensitive") {
+            self.matching = Matching::parse(meta)?;
+        } else if meta.path.is_ident("literal_macro") {
+            self.literal_macro = Some(parse_ident(meta, "macro name")?);
+        } else if meta.path.is_ident("impls") {
+            self.impls = Impls::parse(meta)?;
+        } else {
+            return Err(unknown_key(meta, ENUM_KEYS));
         }
+        Ok(())
     }
 
     /// Applies renaming rules (prefix, suffix, case) to a given string.
     fn rename<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        self.rename_variant(s, &Overrides::default())
+    }
+
+    /// Applies the renaming rules to the name of a variant, with the case,
+    /// prefix and suffix given on the variant taking precedence.
+    fn rename_variant<'a>(&self, s: &'a str, overrides: &Overrides) -> Cow<'a, str> {
+        let case = overrides.case.as_ref().or(self.case.as_ref());
+        self.rename_with_case(s, overrides, case)
+    }
+
+    /// Applies the renaming rules with the alternate case, if there is one.
+    fn rename_alternate(&self, s: &str, overrides: &Overrides) -> Option<String> {
+        let case = self.alternate_case.as_ref()?;
+        Some(self.rename_with_case(s, overrides, Some(case)).into_owned())
+    }
+
+    fn rename_with_case<'a>(
+        &self,
+        s: &'a str,
+        overrides: &Overrides,
+        case: Option<&Case>,
+    ) -> Cow<'a, str> {
         let mut new_name = Cow::Borrowed(s);
 
-        if let Some(prefix) = &self.prefix {
+        if let Some(prefix) = overrides.prefix(self.prefix.as_deref()) {
             new_name = Cow::Owned(format!("{prefix}{new_name}"));
         }
-        if let Some(suffix) = &self.suffix {
+        if let Some(suffix) = overrides.suffix(self.suffix.as_deref()) {
             new_name = Cow::Owned(format!("{new_name}{suffix}"));
         }
-        if let Some(case) = &self.case {
+        if let Some(case) = case {
             new_name = Cow::Owned(case.to_case(&new_name));
         }
         new_name
     }
 }
 
+/// A variant of the enum together with its string representation.
+pub(crate) struct RenamedVariant {
+    /// The identifier of the variant.
+    pub(crate) ident: Ident,
+    /// The fields of the variant, ignored when formatting.
+    pub(crate) fields: syn::Fields,
+    /// The string written by `Display`, empty for a variant skipped from it
+    /// without a placeholder.
+    pub(crate) display: String,
+    /// The string written by `Display` with the `#` flag, if it differs.
+    pub(crate) alternate: Option<String>,
+    /// The strings parsed into the variant, empty if it is never parsed.
+    pub(crate) parsed: Vec<String>,
+    /// Whether the variant can be built when parsing, filling its fields
+    /// with their `Default` value.
+    pub(crate) default_fields: bool,
+    /// Whether unrecognised strings are parsed into this variant.
+    pub(crate) fallback: Option<Fallback>,
+    /// Whether unrecognised strings are captured in the field of this
+    /// variant, which is then displayed as is.
+    pub(crate) other: bool,
+    /// Whether the variant is skipped from `Display` or parsing, which
+    /// leaves it out of the generated tables.
+    pub(crate) skipped: bool,
+    /// The doc comments of the variant, empty if it has none.
+    pub(crate) description: String,
+    /// Values given with `meta(key = value)`.
+    pub(crate) meta: Vec<MetaValue>,
+    /// The group the variant belongs to, if any.
+    pub(crate) group: Option<String>,
+    /// The name of the associated constant holding the string of the
+    /// variant, if it has a fixed string and the name is its own.
+    pub(crate) constant: Option<Ident>,
+}
+
+impl RenamedVariant {
+    /// Returns every string that is parsed into the variant, its main
+    /// name first.
+    pub(crate) fn parse_names(&self) -> impl Iterator<Item = &str> {
+        self.parsed.iter().map(String::as_str)
+    }
+}
+
 /// Stores the names of the variants of an enum with the possibility of adding
-/// renaming information
+/// renaming information, in declaration order
 pub struct Variants {
-    variant_renames: HashMap<Ident, Option<VariantRename>>,
+    variant_attributes: Vec<(Ident, syn::Fields, VariantAttributes)>,
+    /// The keys given with `meta` on any variant, in the order they first
+    /// appear, with the type of their values.
+    pub(crate) meta_keys: Vec<(Ident, String)>,
 }
 
 impl Variants {
     /// Constructs a `Variants` instance by parsing the derive input.
-    pub(crate) fn new(ast: &DeriveInput) -> Self {
+    ///
+    /// Errors found in the attributes of the different variants are combined
+    /// so that they are all reported at once.
+    pub(crate) fn new(ast: &DeriveInput) -> syn::Result<Self> {
         let mut new = Self {
-            variant_renames: HashMap::new(),
+            variant_attributes: Vec::new(),
+            meta_keys: Vec::new(),
         };
 
-        let variants = match ast.data {
-            syn::Data::Enum(ref e) => &e.variants,
-            _ => panic!("EnumToString only works with Enums"),
+        let variants = match &ast.data {
+            syn::Data::Enum(e) => &e.variants,
+            syn::Data::Struct(s) => {
+                return Err(syn::Error::new_spanned(
+                    s.struct_token,
+                    "EnumStringify only works with enums",
+                ))
+            }
+            syn::Data::Union(u) => {
+                return Err(syn::Error::new_spanned(
+                    u.union_token,
+                    "EnumStringify only works with enums",
+                ))
+            }
         };
 
-        variants
+        let mut errors: Option<syn::Error> = None;
+        for variant in variants {
+            if let Err(error) = new.parse_variant_attribute(variant) {
+                combine_error(&mut errors, error);
+            }
+        }
+        if let Some(errors) = errors {
+            return Err(errors);
+        }
+
+        // Only one variant can receive the unrecognised strings.
+        let mut fallbacks = new
+            .variant_attributes
             .iter()
-            .for_each(|variant| new.parse_variant_attribute(variant));
-        new
+            .filter(|(_, _, attributes)| attributes.fallback.is_some() || attributes.other);
+        if let (Some((first, _, _)), Some((second, _, _))) = (fallbacks.next(), fallbacks.next()) {
+            return Err(syn::Error::new_spanned(
+                second,
+                format!(
+                    "only one variant can receive unrecognised strings, `{first}` already does"
+                ),
+            ));
+        }
+
+        new.collect_meta_keys()?;
+        Ok(new)
+    }
+
+    /// Collects the `meta` keys of all variants, checking that the values of
+    /// a key have the same type on every variant.
+    fn collect_meta_keys(&mut self) -> syn::Result<()> {
+        let mut errors: Option<syn::Error> = None;
+        for (_, _, attributes) in &self.variant_attributes {
+            for value in &attributes.meta {
+                match self.meta_keys.iter().find(|(key, _)| *key == value.key) {
+                    None => self.meta_keys.push((value.key.clone(), value.ty.clone())),
+                    Some((_, ty)) if *ty == value.ty => {}
+                    Some((key, ty)) => {
+                        let error = syn::Error::new_spanned(
+                            &value.literal,
+                            format!(
+                                "mismatched types for meta key `{key}`: expected `{ty}`, found `{}`",
+                                value.ty
+                            ),
+                        );
+                        combine_error(&mut errors, error);
+                    }
+                }
+            }
+        }
+        errors.map_or(Ok(()), Err)
     }
 
     /// Parses attributes for a given enum variant.
-    fn parse_variant_attribute(&mut self, variant: &syn::Variant) {
-        let rename = variant
-            .attrs
-            .iter()
-            .filter_map(VariantRename::parse_args)
-            .reduce(|_, new| new);
-        self.variant_renames.insert(variant.ident.clone(), rename);
+    fn parse_variant_attribute(&mut self, variant: &syn::Variant) -> syn::Result<()> {
+        let attributes = VariantAttributes::new(variant)?;
+        self.variant_attributes
+            .push((variant.ident.clone(), variant.fields.clone(), attributes));
+        Ok(())
     }
 
     /// Applies renaming rules to each enum variant name.
-    pub(crate) fn apply(&self, attributes: &Attributes) -> Vec<(syn::Ident, String)> {
-        self.variant_renames
+    ///
+    /// Fails if two variants end up with the same string, as it would be
+    /// ambiguous which one a string is parsed into.
+    pub(crate) fn apply(&self, attributes: &Attributes) -> syn::Result<Vec<RenamedVariant>> {
+        self.check_tables(attributes)?;
+        let mut variants: Vec<RenamedVariant> = self
+            .variant_attributes
             .iter()
-            .map(|(ident, rename)| {
-                let new_name = if let Some(rename) = rename {
-                    rename.0.clone()
+            .map(|(ident, fields, variant_attributes)| {
+                let name = if let Some(rename) = &variant_attributes.rename {
+                    rename.clone()
+                } else {
+                    attributes
+                        .rename_variant(ident.to_string().as_str(), &variant_attributes.overrides)
+                        .into_owned()
+                };
+
+                let display = if variant_attributes.skip_display {
+                    variant_attributes.placeholder.clone().unwrap_or_default()
                 } else {
-                    attributes.rename(ident.to_string().as_str()).into_owned()
+                    variant_attributes
+                        .to
+                        .clone()
+                        .unwrap_or_else(|| name.clone())
                 };
-                (ident.clone(), new_name)
+
+                // Strings given explicitly are kept as they are, unless the
+                // alternate string is given too.
+                let alternate = if variant_attributes.skip_display {
+                    None
+                } else if variant_attributes.alternate.is_some() {
+                    variant_attributes.alternate.clone()
+                } else if variant_attributes.rename.is_some() || variant_attributes.to.is_some() {
+                    None
+                } else {
+                    attributes.rename_alternate(&ident.to_string(), &variant_attributes.overrides)
+                }
+                .filter(|alternate| *alternate != display);
+
+                // The name is only parsed when no `from` is given. Strings
+                // already accepted by the variant are dropped, they would
+                // only produce unreachable arms.
+                let mut parsed: Vec<String> = Vec::new();
+                if !variant_attributes.skip_parse {
+                    let names = if variant_attributes.from.is_empty() {
+                        std::slice::from_ref(&name)
+                    } else {
+                        variant_attributes.from.as_slice()
+                    };
+                    for candidate in names.iter().chain(&variant_attributes.aliases) {
+                        let folded = attributes.matching.fold(candidate);
+                        if parsed.iter().all(|s| attributes.matching.fold(s) != folded) {
+                            parsed.push(candidate.clone());
+                        }
+                    }
+                }
+
+                RenamedVariant {
+                    ident: ident.clone(),
+                    fields: fields.clone(),
+                    display,
+                    alternate,
+                    parsed,
+                    default_fields: variant_attributes.default_fields,
+                    fallback: variant_attributes.fallback,
+                    other: variant_attributes.other,
+                    skipped: variant_attributes.skip_display || variant_attributes.skip_parse,
+                    description: variant_attributes.description.clone(),
+                    meta: variant_attributes.meta.clone(),
+                    group: variant_attributes.group.clone(),
+                    constant: None,
+                }
             })
-            .collect()
+            .collect();
+        check_collisions(&variants, attributes.matching)?;
+        assign_constants(&mut variants);
+        self.check_meta_keys(attributes, &variants)?;
+        Ok(variants)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use quote::quote;
+    /// Checks that the accessors named after the meta keys do not clash with
+    /// the other generated items, reporting the clashes at the first use of
+    /// each key.
+    fn check_meta_keys(
+        &self,
+        attributes: &Attributes,
+        variants: &[RenamedVariant],
+    ) -> syn::Result<()> {
+        let mut errors: Option<syn::Error> = None;
+        for (key, _) in &self.meta_keys {
+            let name = key.to_string();
+            if DERIVED_METHODS.contains(&name.as_str()) {
+                let error = syn::Error::new(
+                    key.span(),
+                    format!("meta key `{key}` would shadow the trait method of the same name"),
+                );
+                combine_error(&mut errors, error);
+                continue;
+            }
+            let clashes = GENERATED_ITEMS.contains(&name.as_str())
+                || attributes.tables.iter().any(|table| {
+                    name == format!("as_{}", table.name) || name == format!("from_{}", table.name)
+                })
+                || variants
+                    .iter()
+                    .any(|variant| variant.constant.as_ref() == Some(key));
+            if clashes {
+                let error = syn::Error::new(
+                    key.span(),
+                    format!("meta key `{key}` clashes with a generated item of the same name"),
+                );
+                combine_error(&mut errors, error);
+            }
+        }
+        errors.map_or(Ok(()), Err)
+    }
 
-    use super::*;
+    /// Checks that the tables the variants refer to are defined on the enum.
+    fn check_tables(&self, attributes: &Attributes) -> syn::Result<()> {
+        let names: Vec<String> = attributes
+            .tables
+            .iter()
+            .map(|table| table.name.to_string())
+            .collect();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        let mut errors: Option<syn::Error> = None;
+        for (_, _, variant_attributes) in &self.variant_attributes {
+            for (table, _) in &variant_attributes.table_renames {
+                let table_name = table.to_string();
+                if names.contains(&table_name.as_str()) {
+                    continue;
+                }
+                let message = match closest(&table_name, &names) {
+                    Some(suggestion) => {
+                        format!("unknown table `{table_name}`, did you mean `{suggestion}`?")
+                    }
+                    None => format!("unknown table `{table_name}`, tables are defined on the enum"),
+                };
+                combine_error(&mut errors, syn::Error::new(table.span(), message));
+            }
+        }
+        errors.map_or(Ok(()), Err)
+    }
 
-    #[test]
-    fn test_parse_string() {
-        assert_eq!(parse_string("\"hello\""), Ok("hello".to_string()));
-        assert_eq!(
-            parse_string("hello"),
-            Err("String must be enclosed in double quotes")
-        );
+    /// Applies the renaming rules of a table to each enum variant name.
+    ///
+    /// Tables only have one string per variant, which is both displayed and
+    /// parsed. Skipped, fallback and `other` variants behave as they do with
+    /// the main strings.
+    pub(crate) fn apply_table(
+        &self,
+        attributes: &Attributes,
+        table: &Table,
+    ) -> syn::Result<Vec<RenamedVariant>> {
+        let variants: Vec<RenamedVariant> = self
+            .variant_attributes
+            .iter()
+            .map(|(ident, fields, variant_attributes)| {
+                let name = variant_attributes
+                    .table_renames
+                    .iter()
+                    .rev()
+                    .find(|(name, _)| *name == table.name)
+                    .map(|(_, rename)| rename.clone())
+                    .unwrap_or_else(|| {
+                        table
+                            .attributes
+                            .rename(ident.to_string().as_str())
+                            .into_owned()
+                    });
+                let display = if variant_attributes.skip_display {
+                    variant_attributes.placeholder.clone().unwrap_or_default()
+                } else {
+                    name.clone()
+                };
+                let parsed = if variant_attributes.skip_parse {
+                    Vec::new()
+                } else {
+                    vec![name]
+                };
+                RenamedVariant {
+                    ident: ident.clone(),
+                    fields: fields.clone(),
+                    display,
+                    alternate: None,
+                    parsed,
+                    default_fields: variant_attributes.default_fields,
+                    fallback: variant_attributes.fallback,
+                    other: variant_attributes.other,
+                    skipped: variant_attributes.skip_display || variant_attributes.skip_parse,
+                    description: variant_attributes.description.clone(),
+                    meta: variant_attributes.meta.clone(),
+                    group: variant_attributes.group.clone(),
+                    constant: None,
+                }
+            })
+            .collect();
+        check_collisions(&variants, attributes.matching)?;
+        Ok(variants)
+    }
+}
 
-        assert_eq!(
-            parse_string("\"hello"),
-            Err("String must be enclosed in double quotes")
-        );
-        assert_eq!(
-            parse_string("hello\""),
-            Err("String must be enclosed in double quotes")
-        );
-        assert_eq!(parse_string("\"he\"llo\""), Ok("he\"llo".to_string()));
+/// Names the associated constants holding the string of each variant, such
+/// as `VARIANT1_STR` for `Variant1`.
+///
+/// Variants without a fixed string, or whose identifier gives no valid
+/// constant name, get no constant. Distinct identifiers can
+/// give the same constant name, as `HttpServer` and `HTTPServer` do, in which
+/// case neither variant gets a constant rather than generating both.
+fn assign_constants(variants: &mut [RenamedVariant]) {
+    for variant in variants.iter_mut() {
+        if !variant.other {
+            let ident = variant.ident.to_string();
+            let name = case::constant_name(ident.trim_start_matches("r#"));
+            // Some identifiers, like `_1`, give no valid constant name.
+            variant.constant = syn::parse_str::<Ident>(&format!("{name}_STR")).ok();
+        }
+    }
+    let constants: Vec<Option<Ident>> = variants
+        .iter()
+        .map(|variant| variant.constant.clone())
+        .collect();
+    for variant in variants.iter_mut() {
+        let shared = variant.constant.is_some()
+            && constants
+                .iter()
+                .filter(|constant| **constant == variant.constant)
+                .count()
+                > 1;
+        if shared {
+            variant.constant = None;
+        }
+    }
+}
 
-        assert_eq!(parse_string("\"\""), Ok(String::new()));
-        assert_eq!(parse_string("\"\"\""), Ok("\"".to_string()));
+/// Checks that no two variants share the same string, as compared when
+/// parsing, reporting every collision at the position of the variant that
+/// causes it. A variant displayed as a string parsed into another variant is
+/// reported too.
+fn check_collisions(variants: &[RenamedVariant], matching: Matching) -> syn::Result<()> {
+    let mut seen: HashMap<Cow<str>, &Ident> = HashMap::new();
+    let mut errors: Option<syn::Error> = None;
+    for variant in variants.iter().filter(|variant| !variant.other) {
+        for name in variant.parse_names() {
+            if let Some(other) = seen.insert(matching.fold(name), &variant.ident) {
+                let error = syn::Error::new(
+                    variant.ident.span(),
+                    format!(
+                        "variants `{other}` and `{}` are both converted to and from the string {name:?}",
+                        variant.ident
+                    ),
+                );
+                combine_error(&mut errors, error);
+            }
+        }
     }
 
-    fn assert_parse_token_list<T>(tokens: TokenStream, expected: Vec<T>)
-    where
-        T: TryFrom<(String, String)> + PartialEq + std::fmt::Debug,
+    // A displayed string must be parsed back into the same variant, if it is
+    // parsed at all. Skipped variants do not round-trip anyway, so their
+    // strings, including placeholders, are exempt.
+    for variant in variants
+        .iter()
+        .filter(|variant| !variant.other && !variant.skipped)
     {
-        let result = parse_token_list::<T>(&tokens).unwrap();
-        assert_eq!(result, expected);
+        let display = &variant.display;
+        match seen.get(&matching.fold(display)) {
+            Some(other) if **other != variant.ident => {
+                let error = syn::Error::new(
+                    variant.ident.span(),
+                    format!(
+                        "variant `{}` is displayed as the string {display:?}, which is parsed into `{other}`",
+                        variant.ident
+                    ),
+                );
+                combine_error(&mut errors, error);
+            }
+            _ => {}
+        }
     }
+    errors.map_or(Ok(()), Err)
+}
 
-    #[test]
-    fn test_parse_token_list_string_string() {
-        assert_parse_token_list::<(String, String)>(quote! {}, vec![]);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        assert_parse_token_list::<(String, String)>(
-            quote! { rename = "hello" },
-            vec![("rename".to_string(), "\"hello\"".to_string())],
-        );
+    fn parse_enum(attribute: syn::Attribute) -> syn::Result<Attributes> {
+        let mut attributes = Attributes::default();
+        attributes.parse_args(&attribute)?;
+        Ok(attributes)
+    }
 
-        assert_parse_token_list(
-            quote! { rename = "hello", rename = "world" },
-            vec![
-                ("rename".to_string(), "\"hello\"".to_string()),
-                ("rename".to_string(), "\"world\"".to_string()),
-            ],
-        );
+    fn parse_variant(attribute: syn::Attribute) -> syn::Result<VariantAttributes> {
+        let fields: syn::Fields = syn::Fields::Unnamed(syn::parse_quote! { (u8) });
+        let mut attributes = VariantAttributes::default();
+        attributes.parse_args(&attribute, &fields)?;
+        Ok(attributes)
     }
 
-    #[derive(Debug, PartialEq)]
-    struct TestStruct(String);
+    fn error_message<T>(result: syn::Result<T>) -> String {
+        match result {
+            Ok(_) => panic!("expected an error"),
+            Err(error) => error.to_string(),
+        }
+    }
 
-    impl TryFrom<(String, String)> for TestStruct {
-        type Error = &'static str;
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("case", "case"), 0);
+        assert_eq!(edit_distance("cas", "case"), 1);
+        assert_eq!(edit_distance("prefx", "prefix"), 1);
+        assert_eq!(edit_distance("sufix", "prefix"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
 
-        fn try_from(value: (String, String)) -> Result<Self, Self::Error> {
-            Ok(Self(parse_string(value.1.as_str())?))
-        }
+    #[test]
+    fn test_closest() {
+        assert_eq!(closest("prefx", ENUM_KEYS), Some("prefix"));
+        assert_eq!(closest("sufix", ENUM_KEYS), Some("suffix"));
+        assert_eq!(closest("cse", ENUM_KEYS), Some("case"));
+        assert_eq!(closest("renam", VARIANT_KEYS), Some("rename"));
+        assert_eq!(closest("something", ENUM_KEYS), None);
     }
 
     #[test]
-    fn test_parse_token_list_struct() {
-        assert_parse_token_list::<TestStruct>(
-            quote! { rename = "hello" },
-            vec![TestStruct("hello".to_string())],
-        );
+    fn test_variant_attributes_parse_args() {
+        let attributes = parse_variant(syn::parse_quote! { #[enum_stringify(rename = "hello")] });
+        assert_eq!(attributes.unwrap().rename, Some("hello".to_string()));
 
-        assert_parse_token_list::<TestStruct>(
-            quote! { rename = "hello", rename = "world" },
-            vec![
-                TestStruct("hello".to_string()),
-                TestStruct("world".to_string()),
-            ],
-        );
+        let attributes = parse_variant(syn::parse_quote! {
+            #[enum_stringify(rename = "hello", rename = "world")]
+        });
+        assert_eq!(attributes.unwrap().rename, Some("world".to_string()));
+
+        let attributes = parse_variant(syn::parse_quote! {
+            #[enum_stringify(alias = "hi", rename = "hello", alias = "hey")]
+        });
+        let attributes = attributes.unwrap();
+        assert_eq!(attributes.rename, Some("hello".to_string()));
+        assert_eq!(attributes.aliases, ["hi", "hey"]);
+
+        let attributes = parse_variant(syn::parse_quote! { #[enum_stringify(fields = "default")] });
+        let attributes = attributes.unwrap();
+        assert_eq!(attributes.rename, None);
+        assert!(attributes.default_fields);
+
+        let attributes = parse_variant(syn::parse_quote! { #[serde(rename = "hello")] });
+        assert_eq!(attributes.unwrap(), VariantAttributes::default());
     }
 
     #[test]
-    fn test_variant_rename_try_from() {
+    fn test_variant_attributes_errors() {
         assert_eq!(
-            VariantRename::try_from(("rename".to_string(), "\"hello\"".to_string())),
-            Ok(VariantRename("hello".to_string()))
+            error_message(parse_variant(
+                syn::parse_quote! { #[enum_stringify(rename = hello)] }
+            )),
+            "expected string literal"
         );
-
         assert_eq!(
-            VariantRename::try_from(("rename".to_string(), "hello".to_string())),
-            Err("String must be enclosed in double quotes")
+            error_message(parse_variant(
+                syn::parse_quote! { #[enum_stringify(rename)] }
+            )),
+            "expected `=`"
         );
-
         assert_eq!(
-            VariantRename::try_from(("not_rename".to_string(), "\"hello\"".to_string())),
-            Err("Not a rename string")
+            error_message(parse_variant(
+                syn::parse_quote! { #[enum_stringify(renam = "a")] }
+            )),
+            "unknown enum_stringify attribute `renam`, did you mean `rename`?"
         );
-    }
-
-    #[test]
-    fn test_parse_token_list_variant_rename() {
-        assert_parse_token_list::<VariantRename>(
-            quote! { rename = "hello" },
-            vec![VariantRename("hello".to_string())],
+        assert_eq!(
+            error_message(parse_variant(
+                syn::parse_quote! { #[enum_stringify(fields = "zero")] }
+            )),
+            "the only supported value for `fields` is \"default\""
         );
 
-        assert_parse_token_list::<VariantRename>(
-            quote! { rename = "hello", rename = "world" },
-            vec![
-                VariantRename("hello".to_string()),
-                VariantRename("world".to_string()),
-            ],
+        let mut attributes = VariantAttributes::default();
+        let attribute = syn::parse_quote! { #[enum_stringify(fields = "default")] };
+        assert_eq!(
+            error_message(attributes.parse_args(&attribute, &syn::Fields::Unit)),
+            "`fields` can only be used on tuple and struct variants"
         );
     }
 
     #[test]
     fn test_attributes_parse_args() {
-        let attribute =
-            syn::parse_quote! { #[enum_stringify(prefix = "pre", suffix = "suf", case = "snake")] };
-        let attributes = Attributes::parse_args(&attribute).unwrap();
+        let attributes = parse_enum(syn::parse_quote! {
+            #[enum_stringify(prefix = "pre", suffix = "suf", case = "snake")]
+        })
+        .unwrap();
         assert_eq!(attributes.prefix, Some("pre".to_string()));
         assert_eq!(attributes.suffix, Some("suf".to_string()));
         assert_eq!(
@@ -340,14 +1423,15 @@ mod tests {
             Some("snake".to_string())
         );
 
-        let attribute = syn::parse_quote! { #[enum_stringify(prefix = "pre", suffix = "suf")] };
-        let attributes = Attributes::parse_args(&attribute).unwrap();
+        let attributes =
+            parse_enum(syn::parse_quote! { #[enum_stringify(prefix = "pre", suffix = "suf")] })
+                .unwrap();
         assert_eq!(attributes.prefix, Some("pre".to_string()));
         assert_eq!(attributes.suffix, Some("suf".to_string()));
         assert_eq!(attributes.case, None);
 
-        let attribute = syn::parse_quote! { #[enum_stringify(case = "snake")] };
-        let attributes = Attributes::parse_args(&attribute).unwrap();
+        let attributes =
+            parse_enum(syn::parse_quote! { #[enum_stringify(case = "snake")] }).unwrap();
         assert_eq!(attributes.prefix, None);
         assert_eq!(attributes.suffix, None);
         assert_eq!(
@@ -355,62 +1439,901 @@ mod tests {
             Some("snake".to_string())
         );
 
-        let attribute = syn::parse_quote! { #[enum_stringify] };
-        assert_eq!(Attributes::parse_args(&attribute), None);
+        let attributes = parse_enum(syn::parse_quote! { #[serde(rename_all = "snake_case")] });
+        assert_eq!(attributes.unwrap(), Attributes::default());
     }
 
     #[test]
-    fn test_attributes_update_attribute() {
+    fn test_attributes_parse_args_overrides() {
         let mut attributes = Attributes::default();
-        attributes.update_attribute(("prefix".to_string(), "\"pre\"".to_string()));
-        assert_eq!(attributes.prefix, Some("pre".to_string()));
-
-        attributes.update_attribute(("suffix".to_string(), "\"suf\"".to_string()));
+        for attribute in [
+            syn::parse_quote! { #[enum_stringify(prefix = "pre", suffix = "suf")] },
+            syn::parse_quote! { #[enum_stringify(case = "snake")] },
+            syn::parse_quote! { #[enum_stringify(prefix = "new1", case = "upper")] },
+        ] {
+            attributes.parse_args(&attribute).unwrap();
+        }
+        assert_eq!(attributes.prefix, Some("new1".to_string()));
         assert_eq!(attributes.suffix, Some("suf".to_string()));
-
-        attributes.update_attribute(("case".to_string(), "\"snake\"".to_string()));
         assert_eq!(
-            attributes.case.clone().map(|a| a.to_string()),
-            Some("snake".to_string())
+            attributes.case.map(|a| a.to_string()),
+            Some("upper".to_string())
         );
+    }
 
-        attributes.update_attribute(("invalid".to_string(), "\"value\"".to_string()));
-        assert_eq!(attributes.prefix, Some("pre".to_string()));
-        assert_eq!(attributes.suffix, Some("suf".to_string()));
+    #[test]
+    fn test_attributes_errors() {
         assert_eq!(
-            attributes.case.clone().map(|a| a.to_string()),
-            Some("snake".to_string())
+            error_message(parse_enum(syn::parse_quote! { #[enum_stringify] })),
+            "expected attribute arguments in parentheses: #[enum_stringify(...)]"
+        );
+        assert_eq!(
+            error_message(parse_enum(
+                syn::parse_quote! { #[enum_stringify(prefix = 1)] }
+            )),
+            "expected string literal"
         );
+        assert_eq!(
+            error_message(parse_enum(
+                syn::parse_quote! { #[enum_stringify(suffix "a")] }
+            )),
+            "expected `=`"
+        );
+        assert_eq!(
+            error_message(parse_enum(
+                syn::parse_quote! { #[enum_stringify(prefx = "a")] }
+            )),
+            "unknown enum_stringify attribute `prefx`, did you mean `prefix`?"
+        );
+        assert_eq!(
+            error_message(parse_enum(
+                syn::parse_quote! { #[enum_stringify(invalid = "a")] }
+            )),
+            "unknown enum_stringify attribute `invalid`, expected one of: prefix, suffix, case, alternate_case, parse_case_insensitive, literal_macro, impls, table"
+        );
+        assert_eq!(
+            error_message(parse_enum(
+                syn::parse_quote! { #[enum_stringify(case = "snak")] }
+            )),
+            "invalid case `snak`, did you mean `snake`?"
+        );
+    }
 
-        attributes.update_attribute(("prefix".to_string(), "\"new1\"".to_string()));
-        assert_eq!(attributes.prefix, Some("new1".to_string()));
+    #[test]
+    fn test_attributes_matching() {
+        let attributes = parse_enum(syn::parse_quote! { #[enum_stringify(case = "snake")] });
+        assert_eq!(attributes.unwrap().matching, Matching::Exact);
 
-        attributes.update_attribute(("suffix".to_string(), "\"new2\"".to_string()));
-        assert_eq!(attributes.suffix, Some("new2".to_string()));
+        let attributes =
+            parse_enum(syn::parse_quote! { #[enum_stringify(parse_case_insensitive)] });
+        assert_eq!(attributes.unwrap().matching, Matching::AsciiCaseInsensitive);
 
-        attributes.update_attribute(("case".to_string(), "\"upper\"".to_string()));
+        let attributes = parse_enum(syn::parse_quote! {
+            #[enum_stringify(parse_case_insensitive = "unicode", case = "snake")]
+        });
         assert_eq!(
-            attributes.case.clone().map(|a| a.to_string()),
-            Some("upper".to_string())
+            attributes.unwrap().matching,
+            Matching::UnicodeCaseInsensitive
+        );
+
+        assert_eq!(
+            error_message(parse_enum(syn::parse_quote! {
+                #[enum_stringify(parse_case_insensitive = "yes")]
+            })),
+            "expected \"ascii\" or \"unicode\""
         );
     }
 
+    #[test]
+    fn test_attributes_literal_macro() {
+        let attributes = parse_enum(syn::parse_quote! { #[enum_stringify(case = "snake")] });
+        assert_eq!(attributes.unwrap().literal_macro, None);
+
+        let attributes =
+            parse_enum(syn::parse_quote! { #[enum_stringify(literal_macro = "level")] });
+        assert_eq!(
+            attributes.unwrap().literal_macro,
+            Some(Ident::new("level", proc_macro2::Span::call_site()))
+        );
+
+        assert_eq!(
+            error_message(parse_enum(syn::parse_quote! {
+                #[enum_stringify(literal_macro = "level!")]
+            })),
+            "`level!` is not a valid macro name"
+        );
+    }
+
+    #[test]
+    fn test_attributes_impls() {
+        let attributes = parse_enum(syn::parse_quote! { #[enum_stringify(case = "snake")] });
+        assert_eq!(attributes.unwrap().impls, Impls::default());
+
+        let attributes =
+            parse_enum(syn::parse_quote! { #[enum_stringify(impls(from_str, try_from_str))] });
+        assert_eq!(
+            attributes.unwrap().impls,
+            Impls {
+                from_str: true,
+                try_from_str: true,
+                ..Impls::NONE
+            }
+        );
+
+        let impls = parse_enum(syn::parse_quote! { #[enum_stringify(impls(display))] })
+            .unwrap()
+            .impls;
+        assert!(impls.display);
+        assert!(!impls.parse_any());
+
+        assert_eq!(
+            error_message(parse_enum(syn::parse_quote! {
+                #[enum_stringify(impls(dispaly))]
+            })),
+            "unknown enum_stringify attribute `dispaly`, did you mean `display`?"
+        );
+
+        let impls = parse_enum(syn::parse_quote! { #[enum_stringify(impls())] })
+            .unwrap()
+            .impls;
+        assert_eq!(impls, Impls::NONE);
+
+        let impls = parse_enum(syn::parse_quote! {
+            #[enum_stringify(impls(display, as_str, variants))]
+        })
+        .unwrap()
+        .impls;
+        assert_eq!(
+            impls,
+            Impls {
+                display: true,
+                as_str: true,
+                variants: true,
+                ..Impls::NONE
+            }
+        );
+    }
+
+    #[test]
+    fn test_matching_fold() {
+        assert_eq!(Matching::Exact.fold("InFo"), "InFo");
+        assert_eq!(Matching::AsciiCaseInsensitive.fold("InFo"), "info");
+        assert_eq!(Matching::AsciiCaseInsensitive.fold("ÉTÉ"), "ÉtÉ");
+        assert_eq!(Matching::UnicodeCaseInsensitive.fold("ÉTÉ"), "été");
+    }
+
     #[test]
     fn test_attributes_rename() {
         let mut attributes = Attributes {
             prefix: Some("pre".to_string()),
             suffix: Some("suf".to_string()),
             case: None,
+            ..Default::default()
         };
 
         assert_eq!(attributes.rename("name"), "prenamesuf");
         assert_eq!(attributes.rename("Name"), "preNamesuf");
         assert_eq!(attributes.rename("NAME"), "preNAMEsuf");
 
-        attributes.update_attribute(("case".to_string(), "\"upper_flat\"".to_string()));
+        attributes
+            .parse_args(&syn::parse_quote! { #[enum_stringify(case = "upper_flat")] })
+            .unwrap();
 
         assert_eq!(attributes.rename("name"), "PRENAMESUF");
         assert_eq!(attributes.rename("Name"), "PRENAMESUF");
         assert_eq!(attributes.rename("NAME"), "PRENAMESUF");
     }
+
+    #[test]
+    fn test_variants_collisions() {
+        let ast: DeriveInput = syn::parse_quote! {
+            #[enum_stringify(case = "flat")]
+            enum Numbers { FooBar, Foobar }
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        let message = error_message(Variants::new(&ast).unwrap().apply(&attributes));
+        assert!(message.starts_with("variants `"));
+        assert!(message.ends_with("are both converted to and from the string \"foobar\""));
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Numbers { One, #[enum_stringify(rename = "One")] Two }
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        assert!(Variants::new(&ast).unwrap().apply(&attributes).is_err());
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Numbers { One, #[enum_stringify(rename = "one")] Two }
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        assert_eq!(
+            Variants::new(&ast)
+                .unwrap()
+                .apply(&attributes)
+                .unwrap()
+                .len(),
+            2
+        );
+
+        let ast: DeriveInput = syn::parse_quote! {
+            #[enum_stringify(parse_case_insensitive)]
+            enum Numbers { One, #[enum_stringify(rename = "one")] Two }
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        assert!(Variants::new(&ast).unwrap().apply(&attributes).is_err());
+    }
+
+    #[test]
+    fn test_variants_aliases() {
+        let ast: DeriveInput = syn::parse_quote! {
+            #[enum_stringify(case = "lower")]
+            enum Level {
+                #[enum_stringify(alias = "warning", alias = "warn", alias = "WARN", alias = "warning")]
+                Warn,
+            }
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        let variants = Variants::new(&ast).unwrap().apply(&attributes).unwrap();
+        assert_eq!(
+            variants[0].parse_names().collect::<Vec<_>>(),
+            ["warn", "warning", "WARN"]
+        );
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Level { Warn, #[enum_stringify(alias = "Warn")] Warning }
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        assert!(Variants::new(&ast).unwrap().apply(&attributes).is_err());
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Level { #[enum_stringify(alias = "w")] Warn(String) }
+        };
+        assert!(error_message(Variants::new(&ast)).starts_with("`alias` can only be used"));
+    }
+
+    #[test]
+    fn test_variant_attributes_fallback() {
+        let attributes = parse_variant(syn::parse_quote! { #[enum_stringify(alias = "a")] });
+        assert_eq!(attributes.unwrap().fallback, None);
+
+        let attributes = parse_variant(syn::parse_quote! { #[enum_stringify(fallback)] });
+        assert_eq!(attributes.unwrap().fallback, Some(Fallback::NonEmpty));
+
+        let attributes = parse_variant(syn::parse_quote! { #[enum_stringify(fallback(empty))] });
+        assert_eq!(attributes.unwrap().fallback, Some(Fallback::Any));
+
+        assert_eq!(
+            error_message(parse_variant(syn::parse_quote! {
+                #[enum_stringify(fallback(emty))]
+            })),
+            "unknown enum_stringify attribute `emty`, did you mean `empty`?"
+        );
+    }
+
+    #[test]
+    fn test_variants_fallback() {
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Level { #[enum_stringify(fallback)] Unknown, #[enum_stringify(fallback)] Other }
+        };
+        assert_eq!(
+            error_message(Variants::new(&ast)),
+            "only one variant can receive unrecognised strings, `Unknown` already does"
+        );
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Level { #[enum_stringify(fallback)] Unknown(String) }
+        };
+        assert!(error_message(Variants::new(&ast)).starts_with("`fallback` can only be used"));
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Level { #[enum_stringify(fallback, fields = "default")] Unknown(String) }
+        };
+        assert!(Variants::new(&ast).is_ok());
+    }
+
+    #[test]
+    fn test_variants_other() {
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Mime { Text, #[enum_stringify(other)] Other(String) }
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        let variants = Variants::new(&ast).unwrap().apply(&attributes).unwrap();
+        assert_eq!(variants.iter().filter(|variant| variant.other).count(), 1);
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Mime { #[enum_stringify(other)] Other }
+        };
+        assert!(error_message(Variants::new(&ast)).starts_with("`other` can only be used"));
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Mime { #[enum_stringify(other)] Other(String, String) }
+        };
+        assert!(error_message(Variants::new(&ast)).starts_with("`other` can only be used"));
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Mime { #[enum_stringify(other, alias = "x")] Other(String) }
+        };
+        assert_eq!(
+            error_message(Variants::new(&ast)),
+            "`alias` can not be used together with `other`"
+        );
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Mime { #[enum_stringify(fallback)] Unknown, #[enum_stringify(other)] Other(String) }
+        };
+        assert_eq!(
+            error_message(Variants::new(&ast)),
+            "only one variant can receive unrecognised strings, `Unknown` already does"
+        );
+    }
+
+    #[test]
+    fn test_variant_attributes_skip() {
+        let attributes = parse_variant(syn::parse_quote! { #[enum_stringify(skip)] }).unwrap();
+        assert!(attributes.skip_display && attributes.skip_parse);
+        assert_eq!(attributes.placeholder, None);
+
+        let attributes =
+            parse_variant(syn::parse_quote! { #[enum_stringify(skip = "<hidden>")] }).unwrap();
+        assert!(attributes.skip_display && attributes.skip_parse);
+        assert_eq!(attributes.placeholder, Some("<hidden>".to_string()));
+
+        let attributes =
+            parse_variant(syn::parse_quote! { #[enum_stringify(skip_display = "?")] }).unwrap();
+        assert!(attributes.skip_display && !attributes.skip_parse);
+        assert_eq!(attributes.placeholder, Some("?".to_string()));
+
+        let attributes =
+            parse_variant(syn::parse_quote! { #[enum_stringify(skip_parse)] }).unwrap();
+        assert!(!attributes.skip_display && attributes.skip_parse);
+
+        assert_eq!(
+            error_message(parse_variant(
+                syn::parse_quote! { #[enum_stringify(skip_parse = "?")] }
+            )),
+            "expected `,`"
+        );
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Level { #[enum_stringify(skip_parse, alias = "w")] Warn }
+        };
+        assert_eq!(
+            error_message(Variants::new(&ast)),
+            "`alias` can not be used on a variant skipped when parsing"
+        );
+    }
+
+    #[test]
+    fn test_variants_skip() {
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Level {
+                Info,
+                #[enum_stringify(skip_parse)]
+                Internal,
+                #[enum_stringify(skip = "-", rename = "Info")]
+                Sentinel,
+            }
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        let variants = Variants::new(&ast).unwrap().apply(&attributes).unwrap();
+        assert_eq!(variants[1].display, "Internal");
+        assert_eq!(variants[1].parse_names().count(), 0);
+        assert_eq!(variants[2].display, "-");
+        assert_eq!(variants[2].parse_names().count(), 0);
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Level { #[enum_stringify(fallback, skip_parse)] Unknown }
+        };
+        assert_eq!(
+            error_message(Variants::new(&ast)),
+            "`fallback` can not be used on a variant skipped when parsing"
+        );
+    }
+
+    #[test]
+    fn test_variants_to_from() {
+        let ast: DeriveInput = syn::parse_quote! {
+            #[enum_stringify(case = "upper")]
+            enum Method {
+                #[enum_stringify(to = "GET")]
+                Get,
+                #[enum_stringify(from = "post", from = "Post", alias = "p")]
+                Post,
+                #[enum_stringify(to = "PUT", from = "put")]
+                Put,
+            }
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        let variants = Variants::new(&ast).unwrap().apply(&attributes).unwrap();
+
+        assert_eq!(variants[0].display, "GET");
+        assert_eq!(variants[0].parsed, ["GET"]);
+        assert_eq!(variants[1].display, "POST");
+        assert_eq!(variants[1].parsed, ["post", "Post", "p"]);
+        assert_eq!(variants[2].display, "PUT");
+        assert_eq!(variants[2].parsed, ["put"]);
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Method { #[enum_stringify(rename = "a", to = "b")] Get }
+        };
+        assert_eq!(
+            error_message(Variants::new(&ast)),
+            "`rename` can not be used together with `to`, use `from` instead"
+        );
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Method { #[enum_stringify(skip_parse, from = "b")] Get }
+        };
+        assert_eq!(
+            error_message(Variants::new(&ast)),
+            "`from` can not be used on a variant skipped when parsing"
+        );
+
+        let ast: DeriveInput = syn::parse_quote! {
+            #[enum_stringify(case = "lower")]
+            enum Letter { #[enum_stringify(to = "b")] A, B }
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        assert_eq!(
+            error_message(Variants::new(&ast).unwrap().apply(&attributes)),
+            "variant `A` is displayed as the string \"b\", which is parsed into `B`"
+        );
+
+        let ast: DeriveInput = syn::parse_quote! {
+            #[enum_stringify(parse_case_insensitive)]
+            enum Letter { #[enum_stringify(to = "b", from = "a")] A, B }
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        assert_eq!(
+            error_message(Variants::new(&ast).unwrap().apply(&attributes)),
+            "variant `A` is displayed as the string \"b\", which is parsed into `B`"
+        );
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Letter { #[enum_stringify(skip_display = "B")] A, B }
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        assert!(Variants::new(&ast).unwrap().apply(&attributes).is_ok());
+    }
+
+    #[test]
+    fn test_variants_alternate() {
+        let ast: DeriveInput = syn::parse_quote! {
+            #[enum_stringify(case = "kebab", alternate_case = "title")]
+            enum Status {
+                InProgress,
+                #[enum_stringify(alternate = "All done")]
+                Done,
+                #[enum_stringify(rename = "n/a")]
+                NotApplicable,
+                #[enum_stringify(skip_display = "?")]
+                Unknown,
+                Blocked,
+            }
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        let variants = Variants::new(&ast).unwrap().apply(&attributes).unwrap();
+        assert_eq!(variants[0].display, "in-progress");
+        assert_eq!(variants[0].alternate, Some("In Progress".to_string()));
+        assert_eq!(variants[1].alternate, Some("All done".to_string()));
+        assert_eq!(variants[2].alternate, None);
+        assert_eq!(variants[3].alternate, None);
+        assert_eq!(variants[4].alternate, Some("Blocked".to_string()));
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Status { #[enum_stringify(skip_display, alternate = "a")] Unknown }
+        };
+        assert_eq!(
+            error_message(Variants::new(&ast)),
+            "`alternate` can not be used on a variant skipped from Display"
+        );
+    }
+
+    #[test]
+    fn test_attributes_tables() {
+        let ast: DeriveInput = syn::parse_quote! {
+            #[enum_stringify(case = "lower")]
+            #[enum_stringify(table = "short", case = "upper_flat")]
+            #[enum_stringify(prefix = "ui.", table = "long")]
+            #[enum_stringify(table = "short", prefix = "S")]
+            enum Status {}
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        assert_eq!(attributes.prefix, None);
+        assert_eq!(attributes.tables.len(), 2);
+        assert_eq!(attributes.tables[0].name, "short");
+        assert_eq!(
+            attributes.tables[0].attributes.rename("InProgress"),
+            "SINPROGRESS"
+        );
+        assert_eq!(attributes.tables[1].name, "long");
+        assert_eq!(attributes.tables[1].attributes.rename("Done"), "ui.Done");
+
+        assert_eq!(
+            error_message(parse_enum(syn::parse_quote! {
+                #[enum_stringify(table = "short", impls(display))]
+            })),
+            "`impls` can not be used together with `table`"
+        );
+        assert_eq!(
+            error_message(parse_enum(syn::parse_quote! {
+                #[enum_stringify(cse = "upper", table = "short")]
+            })),
+            "unknown enum_stringify attribute `cse`, did you mean `case`?"
+        );
+        assert_eq!(
+            error_message(parse_enum(syn::parse_quote! {
+                #[enum_stringify(table = "short table")]
+            })),
+            "`short table` is not a valid table name"
+        );
+        assert_eq!(
+            error_message(parse_enum(syn::parse_quote! {
+                #[enum_stringify(table = "str")]
+            })),
+            "table name `str` clashes with the generated method `as_str`"
+        );
+        assert_eq!(
+            error_message(parse_enum(syn::parse_quote! {
+                #[enum_stringify(table = "str_const", case = "upper")]
+            })),
+            "table name `str_const` clashes with the generated method `from_str_const`"
+        );
+        assert_eq!(
+            error_message(parse_enum(syn::parse_quote! {
+                #[enum_stringify(table = "Code")]
+            })),
+            "table name `Code` is not in snake case, like `short` or `api_code`"
+        );
+        assert_eq!(
+            error_message(parse_enum(syn::parse_quote! {
+                #[enum_stringify(table = "api__code")]
+            })),
+            "table name `api__code` is not in snake case, like `short` or `api_code`"
+        );
+    }
+
+    #[test]
+    fn test_variants_tables() {
+        let ast: DeriveInput = syn::parse_quote! {
+            #[enum_stringify(table = "code", case = "upper_flat")]
+            enum Status {
+                InProgress,
+                #[enum_stringify(table = "code", rename = "OK")]
+                #[enum_stringify(rename = "finished")]
+                Done,
+                #[enum_stringify(skip_parse)]
+                Unknown,
+            }
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        let variants = Variants::new(&ast).unwrap();
+        let table = variants
+            .apply_table(&attributes, &attributes.tables[0])
+            .unwrap();
+        assert_eq!(table[0].display, "INPROGRESS");
+        assert_eq!(table[0].parsed, ["INPROGRESS"]);
+        assert_eq!(table[1].display, "OK");
+        assert_eq!(table[2].parsed, Vec::<String>::new());
+
+        let main = variants.apply(&attributes).unwrap();
+        assert_eq!(main[1].display, "finished");
+
+        let ast: DeriveInput = syn::parse_quote! {
+            #[enum_stringify(table = "code")]
+            enum Status { #[enum_stringify(table = "cod", rename = "a")] Done }
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        assert_eq!(
+            error_message(Variants::new(&ast).unwrap().apply(&attributes)),
+            "unknown table `cod`, did you mean `code`?"
+        );
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Status { #[enum_stringify(table = "code", alias = "a")] Done }
+        };
+        assert_eq!(
+            error_message(Variants::new(&ast)),
+            "`alias` can not be used together with `table`"
+        );
+    }
+
+    #[test]
+    fn test_parse_doc() {
+        let variant: syn::Variant = syn::parse_quote! {
+            /// Waiting for a reply.
+            ///
+            ///   Indented.
+            #[enum_stringify(rename = "w")]
+            #[doc = "Last line."]
+            #[doc(hidden)]
+            Waiting
+        };
+        assert_eq!(
+            parse_doc(&variant.attrs),
+            "Waiting for a reply.\n\nIndented.\nLast line."
+        );
+
+        let variant: syn::Variant = syn::parse_quote! { Done };
+        assert_eq!(parse_doc(&variant.attrs), "");
+
+        let variant: syn::Variant = syn::parse_quote! {
+            /** Block comment,
+            on two lines. */
+            Done
+        };
+        assert_eq!(parse_doc(&variant.attrs), "Block comment,\non two lines.");
+    }
+
+    #[test]
+    fn test_variants_meta() {
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Fruit {
+                #[enum_stringify(meta(color = "red", weight = 3))]
+                Apple,
+                #[enum_stringify(meta(weight = 7, sour = true), meta(ratio = 0.5f32))]
+                Lemon,
+                Plum,
+            }
+        };
+        let variants = Variants::new(&ast).unwrap();
+        let keys: Vec<String> = variants
+            .meta_keys
+            .iter()
+            .map(|(key, ty)| format!("{key}: {ty}"))
+            .collect();
+        assert_eq!(
+            keys,
+            [
+                "color: &'static str",
+                "weight: i64",
+                "sour: bool",
+                "ratio: f32"
+            ]
+        );
+        let attributes = Attributes::new(&ast).unwrap();
+        let renamed = variants.apply(&attributes).unwrap();
+        assert_eq!(renamed[1].meta.len(), 3);
+        assert_eq!(renamed[1].meta[2].string, "0.5");
+        assert_eq!(renamed[1].meta[2].literal.to_string(), "0.5f32");
+        assert!(renamed[2].meta.is_empty());
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Fruit {
+                #[enum_stringify(meta(weight = 3))]
+                Apple,
+                #[enum_stringify(meta(weight = "7"))]
+                Lemon,
+            }
+        };
+        assert_eq!(
+            error_message(Variants::new(&ast)),
+            "mismatched types for meta key `weight`: expected `i64`, found `&'static str`"
+        );
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Fruit { #[enum_stringify(meta(weight = 3), meta(weight = 4))] Apple }
+        };
+        assert_eq!(
+            error_message(Variants::new(&ast)),
+            "duplicate meta key `weight`"
+        );
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Fruit { #[enum_stringify(meta(weight = b"3"))] Apple }
+        };
+        assert_eq!(
+            error_message(Variants::new(&ast)),
+            "`meta` values must be strings, numbers, booleans or characters"
+        );
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Fruit { #[enum_stringify(meta(type = "x"))] Apple }
+        };
+        assert_eq!(
+            error_message(Variants::new(&ast)),
+            "`type` is a keyword and can not be used as a meta key"
+        );
+
+        let clash = |ast: DeriveInput| {
+            let attributes = Attributes::new(&ast).unwrap();
+            error_message(Variants::new(&ast).unwrap().apply(&attributes))
+        };
+        assert_eq!(
+            clash(syn::parse_quote! {
+                enum Fruit { #[enum_stringify(group = "x", meta(group = "y"))] Apple }
+            }),
+            "meta key `group` clashes with a generated item of the same name"
+        );
+        assert_eq!(
+            clash(syn::parse_quote! {
+                enum Fruit { #[enum_stringify(meta(description = "x"))] Apple }
+            }),
+            "meta key `description` clashes with a generated item of the same name"
+        );
+        assert_eq!(
+            clash(syn::parse_quote! {
+                #[enum_stringify(table = "short")]
+                enum Fruit { #[enum_stringify(meta(as_short = "x"))] Apple }
+            }),
+            "meta key `as_short` clashes with a generated item of the same name"
+        );
+        assert_eq!(
+            clash(syn::parse_quote! {
+                enum Fruit { Apple, #[enum_stringify(meta(APPLE_STR = "x"))] Lemon }
+            }),
+            "meta key `APPLE_STR` clashes with a generated item of the same name"
+        );
+        assert_eq!(
+            clash(syn::parse_quote! {
+                enum Fruit { #[enum_stringify(meta(clone = "x"))] Apple }
+            }),
+            "meta key `clone` would shadow the trait method of the same name"
+        );
+    }
+
+    #[test]
+    fn test_variants_constants() {
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Proto {
+                HttpServer,
+                Ftp,
+                HTTPServer,
+                #[enum_stringify(skip_display)]
+                Hidden,
+                #[enum_stringify(other)]
+                Other(String),
+            }
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        let variants = Variants::new(&ast).unwrap().apply(&attributes).unwrap();
+        let constants: Vec<Option<String>> = variants
+            .iter()
+            .map(|variant| variant.constant.as_ref().map(ToString::to_string))
+            .collect();
+        assert_eq!(
+            constants,
+            [
+                None,
+                Some("FTP_STR".to_string()),
+                None,
+                Some("HIDDEN_STR".to_string()),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn test_variants_constants_invalid_names() {
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Kw { r#type, _1, _2, Other }
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        let variants = Variants::new(&ast).unwrap().apply(&attributes).unwrap();
+        let constants: Vec<Option<String>> = variants
+            .iter()
+            .map(|variant| variant.constant.as_ref().map(ToString::to_string))
+            .collect();
+        assert_eq!(
+            constants,
+            [
+                Some("TYPE_STR".to_string()),
+                None,
+                None,
+                Some("OTHER_STR".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_variants_groups() {
+        let ast: DeriveInput = syn::parse_quote! {
+            #[enum_stringify(table = "short", case = "upper")]
+            enum Status {
+                #[enum_stringify(group = "success")]
+                Ok,
+                #[enum_stringify(group = "client_error")]
+                TooManyRequests,
+                Unknown,
+            }
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        let variants = Variants::new(&ast).unwrap();
+        let groups = |renamed: Vec<RenamedVariant>| -> Vec<Option<String>> {
+            renamed.into_iter().map(|variant| variant.group).collect()
+        };
+        let expected = [
+            Some("success".to_string()),
+            Some("client_error".to_string()),
+            None,
+        ];
+        assert_eq!(groups(variants.apply(&attributes).unwrap()), expected);
+        let table = &attributes.tables[0];
+        assert_eq!(
+            groups(variants.apply_table(&attributes, table).unwrap()),
+            expected
+        );
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Status {
+                #[enum_stringify(group = "client_error")]
+                #[enum_stringify(group = "retryable")]
+                TooManyRequests,
+            }
+        };
+        assert_eq!(
+            error_message(Variants::new(&ast)),
+            "duplicate `group`, a variant belongs to at most one group"
+        );
+    }
+
+    #[test]
+    fn test_variants_overrides() {
+        let ast: DeriveInput = syn::parse_quote! {
+            #[enum_stringify(prefix = "app.", suffix = "_event", case = "snake", alternate_case = "title")]
+            enum Event {
+                UserLogin,
+                #[enum_stringify(case = "kebab")]
+                UserLogout,
+                #[enum_stringify(prefix = "sys.", no_suffix)]
+                Shutdown,
+                #[enum_stringify(no_prefix, suffix = "_reply")]
+                Ping,
+            }
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        let variants = Variants::new(&ast).unwrap().apply(&attributes).unwrap();
+        let displayed: Vec<&str> = variants
+            .iter()
+            .map(|variant| variant.display.as_str())
+            .collect();
+        assert_eq!(
+            displayed,
+            [
+                "app.user_login_event",
+                "app.user-logout-event",
+                "sys.shutdown",
+                "ping_reply"
+            ]
+        );
+        assert_eq!(variants[3].alternate, Some("Ping Reply".to_string()));
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Event { #[enum_stringify(rename = "ping", case = "upper")] Ping }
+        };
+        assert_eq!(
+            error_message(Variants::new(&ast)),
+            "`case` can not be used together with `rename`"
+        );
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Event { #[enum_stringify(prefix = "a", no_prefix)] Ping }
+        };
+        assert_eq!(
+            error_message(Variants::new(&ast)),
+            "`prefix` can not be used together with `no_prefix`"
+        );
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Event { #[enum_stringify(other, no_suffix)] Other(String) }
+        };
+        assert_eq!(
+            error_message(Variants::new(&ast)),
+            "`no_suffix` can not be used together with `other`"
+        );
+    }
+
+    #[test]
+    fn test_variants_only_enums() {
+        let ast: DeriveInput = syn::parse_quote! { struct Numbers; };
+        assert_eq!(
+            error_message(Variants::new(&ast)),
+            "EnumStringify only works with enums"
+        );
+
+        let ast: DeriveInput = syn::parse_quote! { union Numbers { a: u8 } };
+        assert_eq!(
+            error_message(Variants::new(&ast)),
+            "EnumStringify only works with enums"
+        );
+    }
 }
@@ -1,6 +1,30 @@
 use std::fmt::Display;
 
-use convert_case::Casing;
+use convert_case::{Boundary, Casing};
+use syn::{meta::ParseNestedMeta, LitStr};
+
+use crate::attributes::closest;
+
+/// Names of the supported casing styles, as written in the `case` attribute.
+const NAMES: &[&str] = &[
+    "upper",
+    "lower",
+    "title",
+    "toggle",
+    "camel",
+    "pascal",
+    "upper_camel",
+    "snake",
+    "upper_snake",
+    "screaming_snake",
+    "kebab",
+    "cobol",
+    "upper_kebab",
+    "train",
+    "flat",
+    "upper_flat",
+    "alternating",
+];
 
 /// Wrapper struct around `convert_case::Case` to represent different casing styles.
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -73,12 +97,45 @@ impl Display for Case {
 }
 
 impl Case {
+    /// Parses the value of a `case = "..."` argument, reporting unknown
+    /// casing styles at the position of the value.
+    pub(crate) fn parse(meta: &ParseNestedMeta) -> syn::Result<Self> {
+        let value = meta.value()?.parse::<LitStr>()?;
+        Self::try_from(format!("\"{}\"", value.value())).map_err(|_| {
+            let case = value.value();
+            let message = match closest(&case, NAMES) {
+                Some(suggestion) => format!("invalid case `{case}`, did you mean `{suggestion}`?"),
+                None => format!(
+                    "invalid case `{case}`, expected one of: {}",
+                    NAMES.join(", ")
+                ),
+            };
+            syn::Error::new_spanned(&value, message)
+        })
+    }
+
     /// Applies the stored casing style to the given string `s` and returns the formatted result.
     pub(crate) fn to_case(&self, s: &str) -> String {
         s.to_case(self.0)
     }
 }
 
+/// Converts a variant identifier to the name of an associated constant, in
+/// screaming snake case.
+///
+/// Words are only split on underscores and before capital letters, so that
+/// digits stay attached to the word before them: `Variant1` gives `VARIANT1`.
+pub(crate) fn constant_name(ident: &str) -> String {
+    ident
+        .with_boundaries(&[
+            Boundary::Underscore,
+            Boundary::LowerUpper,
+            Boundary::DigitUpper,
+            Boundary::Acronym,
+        ])
+        .to_case(convert_case::Case::ScreamingSnake)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,6 +185,13 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_names_are_all_cases() {
+        let names: Vec<String> = NAMES.iter().map(|name| format!("\"{name}\"")).collect();
+        let cases: Vec<&str> = all_cases().into_iter().map(|(name, _)| name).collect();
+        assert_eq!(names, cases);
+    }
+
     #[test]
     fn test_try_from_string_rejects_invalid_input() {
         let err = Case::try_from("invalid_value".to_string());
@@ -148,6 +212,16 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_constant_name() {
+        assert_eq!(constant_name("Variant1"), "VARIANT1");
+        assert_eq!(constant_name("DarkRed"), "DARK_RED");
+        assert_eq!(constant_name("HTTPServer"), "HTTP_SERVER");
+        assert_eq!(constant_name("Ipv4Addr"), "IPV4_ADDR");
+        assert_eq!(constant_name("snake_case"), "SNAKE_CASE");
+        assert_eq!(constant_name("A"), "A");
+    }
+
     // ------------------------------------------------------------------------
     // Display Tests
     // ------------------------------------------------------------------------
@@ -97,6 +97,182 @@
 //! assert_eq!(Istari::try_from("Ólorin").unwrap(), Istari::Gandalf);
 //! ```
 //!
+//! ## Per-Variant Case, Prefix and Suffix
+//!
+//! `case`, `prefix` and `suffix` can also be set on a variant, replacing
+//! those of the enum for this variant only, and `no_prefix` and `no_suffix`
+//! leave out the prefix and suffix of the enum:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify, Debug, PartialEq)]
+//! #[enum_stringify(prefix = "log.", case = "lower")]
+//! enum Level {
+//!     Info,
+//!     #[enum_stringify(case = "upper")]
+//!     Warn,
+//!     #[enum_stringify(no_prefix)]
+//!     Error,
+//!     #[enum_stringify(prefix = "dbg.")]
+//!     Debug,
+//! }
+//!
+//! assert_eq!(Level::Info.to_string(), "log.info");
+//! assert_eq!(Level::Warn.to_string(), "LOG.WARN");
+//! assert_eq!(Level::Error.to_string(), "error");
+//! assert_eq!(Level::try_from("dbg.debug").unwrap(), Level::Debug);
+//! ```
+//!
+//! They can not be combined with `rename`, which already gives the exact
+//! string. The alternate string still uses `alternate_case`, with the prefix
+//! and suffix of the variant, and the strings of named tables are unaffected.
+//!
+//! ## Different Names for Display and Parsing
+//!
+//! `to` sets the string written by `Display`, and `from` (which can be
+//! repeated) sets the strings accepted when parsing, instead of the name:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify, Debug, PartialEq)]
+//! enum Method {
+//!     #[enum_stringify(to = "GET", from = "get", from = "Get")]
+//!     Get,
+//!     #[enum_stringify(to = "POST")]
+//!     Post,
+//! }
+//!
+//! assert_eq!(Method::Get.to_string(), "GET");
+//! assert_eq!(Method::try_from("get").unwrap(), Method::Get);
+//! assert_eq!(Method::try_from("Get").unwrap(), Method::Get);
+//! assert!(Method::try_from("GET").is_err());
+//!
+//! assert_eq!(Method::Post.to_string(), "POST");
+//! assert_eq!(Method::try_from("Post").unwrap(), Method::Post);
+//! ```
+//!
+//! Each key only affects its own direction: a variant with `to` and no
+//! `from` is still parsed from its default name. `rename` sets both and can
+//! not be combined with either. A variant can not be displayed as a string
+//! that is parsed into another variant, as it would not round-trip:
+//!
+//! ```compile_fail
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify)]
+//! #[enum_stringify(case = "lower")]
+//! enum Letter {
+//!     #[enum_stringify(to = "b")] // variant `A` is displayed as the string "b", which is parsed into `B`
+//!     A,
+//!     B,
+//! }
+//! ```
+//!
+//! ## Aliases
+//!
+//! A variant can accept other strings when parsing, while `Display` keeps
+//! using its name:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify, Debug, PartialEq)]
+//! #[enum_stringify(case = "lower")]
+//! enum Level {
+//!     Info,
+//!     #[enum_stringify(alias = "warning", alias = "w")]
+//!     Warn,
+//! }
+//!
+//! assert_eq!(Level::Warn.to_string(), "warn");
+//! assert_eq!(Level::try_from("warn").unwrap(), Level::Warn);
+//! assert_eq!(Level::try_from("warning").unwrap(), Level::Warn);
+//! assert_eq!(Level::try_from("w").unwrap(), Level::Warn);
+//! ```
+//!
+//! ## Fallback Variant
+//!
+//! One variant can be marked as the fallback, unrecognised strings are then
+//! parsed into it instead of returning an error. The empty string is still
+//! rejected, unless the variant is marked with `fallback(empty)`:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify, Debug, PartialEq)]
+//! enum Color {
+//!     Red,
+//!     Green,
+//!     #[enum_stringify(fallback)]
+//!     Unknown,
+//! }
+//!
+//! assert_eq!(Color::try_from("Red").unwrap(), Color::Red);
+//! assert_eq!(Color::try_from("Purple").unwrap(), Color::Unknown);
+//! assert_eq!(Color::try_from("Unknown").unwrap(), Color::Unknown);
+//! assert!(Color::try_from("").is_err());
+//! ```
+//!
+//! ## Capturing Unknown Strings
+//!
+//! A variant with a single string field can be marked with `other` to
+//! capture any unrecognised string. The captured string is displayed back as
+//! is, so the conversion is lossless:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify, Debug, PartialEq)]
+//! #[enum_stringify(case = "lower")]
+//! enum Mime {
+//!     Json,
+//!     Html,
+//!     #[enum_stringify(other)]
+//!     Other(String),
+//! }
+//!
+//! assert_eq!(Mime::try_from("json").unwrap(), Mime::Json);
+//! let other = Mime::try_from("text/csv").unwrap();
+//! assert_eq!(other, Mime::Other("text/csv".to_string()));
+//! assert_eq!(other.to_string(), "text/csv");
+//! ```
+//!
+//! The field can be of any type implementing `From<&str>` and `Display`.
+//!
+//! ## Skipping Variants
+//!
+//! Variants marked with `skip_parse` are never produced by parsing, and those
+//! marked with `skip_display` are not displayed: they are written as an
+//! empty string, or as the placeholder given with `skip_display = "..."`.
+//! `skip` combines both:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify, Debug, PartialEq)]
+//! enum State {
+//!     Running,
+//!     #[enum_stringify(skip_parse)]
+//!     Starting,
+//!     #[enum_stringify(skip = "<internal>")]
+//!     Sentinel,
+//!     #[enum_stringify(skip_display)]
+//!     Hidden,
+//! }
+//!
+//! assert_eq!(State::Starting.to_string(), "Starting");
+//! assert!(State::try_from("Starting").is_err());
+//!
+//! assert_eq!(State::Sentinel.to_string(), "<internal>");
+//! assert!(State::try_from("Sentinel").is_err());
+//! assert!(State::try_from("<internal>").is_err());
+//!
+//! assert_eq!(State::Hidden.to_string(), "");
+//! assert_eq!(State::try_from("Hidden").unwrap(), State::Hidden);
+//! ```
+//!
 //! ## Using All Options Together
 //!
 //! You can combine all options: renaming, prefix, suffix, and case conversion.
@@ -133,30 +309,580 @@
 //!     Error3,
 //! }
 //!
-//! assert_eq!(Status::Okk.to_string(), "okay");
-//! assert_eq!(Status::Error3.to_string(), "PRE ERROR 3 POST");
+//! assert_eq!(Status::Okk.to_string(), "okay");
+//! assert_eq!(Status::Error3.to_string(), "PRE ERROR 3 POST");
+//!
+//! assert_eq!(Status::try_from("okay").unwrap(), Status::Okk);
+//! assert_eq!(Status::try_from("PRE ERROR 3 POST").unwrap(), Status::Error3);
+//! ```
+//!
+//! ## Case-Insensitive Parsing
+//!
+//! Parsing can accept any casing of the names, while `Display` still uses the
+//! configured one. Strings are compared without allocating:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify, Debug, PartialEq)]
+//! #[enum_stringify(case = "lower", parse_case_insensitive)]
+//! enum Level {
+//!     Info,
+//!     Warn,
+//! }
+//!
+//! assert_eq!(Level::Info.to_string(), "info");
+//! assert_eq!(Level::try_from("INFO").unwrap(), Level::Info);
+//! assert_eq!(Level::try_from("Warn").unwrap(), Level::Warn);
+//! ```
+//!
+//! Only ASCII letters are compared case-insensitively by default, use
+//! `parse_case_insensitive = "unicode"` to ignore the casing of any letter:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify, Debug, PartialEq)]
+//! #[enum_stringify(parse_case_insensitive = "unicode")]
+//! enum Season {
+//!     Été,
+//!     Hiver,
+//! }
+//!
+//! assert_eq!(Season::try_from("ÉTÉ").unwrap(), Season::Été);
+//! ```
+//!
+//! ## Variants with Fields
+//!
+//! Tuple and struct variants are supported. Their fields are ignored when
+//! formatting, but as they can not be built from a string alone they are not
+//! parsed by default:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify, Debug, PartialEq)]
+//! enum Event {
+//!     Started,
+//!     Moved(i32, i32),
+//!     Resized { width: u32, height: u32 },
+//! }
+//!
+//! assert_eq!(Event::Moved(1, 2).to_string(), "Moved");
+//! assert_eq!(Event::Resized { width: 1, height: 2 }.to_string(), "Resized");
+//!
+//! assert_eq!(Event::try_from("Started").unwrap(), Event::Started);
+//! assert!(Event::try_from("Moved").is_err());
+//! ```
+//!
+//! A variant can opt in to parsing with `fields = "default"`, every field is
+//! then filled with its [`Default`] value:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify, Debug, PartialEq)]
+//! enum Event {
+//!     #[enum_stringify(fields = "default")]
+//!     Moved(i32, i32),
+//!     #[enum_stringify(fields = "default")]
+//!     Resized { width: u32, height: u32 },
+//! }
+//!
+//! assert_eq!(Event::try_from("Moved").unwrap(), Event::Moved(0, 0));
+//! assert_eq!(
+//!     Event::try_from("Resized").unwrap(),
+//!     Event::Resized { width: 0, height: 0 }
+//! );
+//! ```
+//!
+//! ## Choosing the Implementations
+//!
+//! `impls(...)` selects the items to generate, so that the others can be
+//! written by hand. It accepts the traits `display`, `from_str`,
+//! `try_from_str` and `try_from_string`, and the inherent items `as_str`,
+//! `from_str_const`, `constants` (the `_STR` constants), `variants`
+//! (`VARIANTS`, `NAMES`, `COUNT` and `iter`) and `description` (`description`
+//! and `DESCRIPTIONS`). All of them are generated by default:
+//!
+//! ```
+//! use std::fmt;
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify, Debug, PartialEq)]
+//! #[enum_stringify(case = "lower", impls(from_str, try_from_str, try_from_string, as_str))]
+//! enum Level {
+//!     Info,
+//!     Warn,
+//! }
+//!
+//! impl fmt::Display for Level {
+//!     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+//!         write!(f, "level {}", self.as_str())
+//!     }
+//! }
+//!
+//! assert_eq!(Level::Info.to_string(), "level info");
+//! assert_eq!(Level::try_from("warn").unwrap(), Level::Warn);
+//! ```
+//!
+//! The parse error type is only generated along with one of the parsing
+//! traits, and `impls()` generates none of the items. Groups, metadata and
+//! tables are generated whenever they are used.
+//!
+//! ## Generics
+//!
+//! Generic parameters, lifetimes and where clauses are carried over to the
+//! generated implementations:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify, Debug, PartialEq)]
+//! enum Event<'a, T> {
+//!     Started,
+//!     Message(&'a str),
+//!     Payload { value: T },
+//! }
+//!
+//! assert_eq!(Event::<u8>::Message("hello").to_string(), "Message");
+//! assert_eq!(Event::Payload { value: 42 }.to_string(), "Payload");
+//! assert_eq!(Event::<u8>::try_from("Started").unwrap(), Event::Started);
+//! ```
+//!
+//! ## Formatting Options
+//!
+//! The names are written as they are, and `Display` honours the width, fill,
+//! alignment and precision of the format string, like it does for `str`:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify)]
+//! #[enum_stringify(case = "lower")]
+//! enum Level {
+//!     Info,
+//!     #[enum_stringify(rename = "{warn}")]
+//!     Warn,
+//! }
+//!
+//! assert_eq!(format!("[{:<6}]", Level::Info), "[info  ]");
+//! assert_eq!(format!("[{:>7}]", Level::Warn), "[ {warn}]");
+//! assert_eq!(format!("[{:-^8}]", Level::Info), "[--info--]");
+//! ```
+//!
+//! ## Alternate Representation
+//!
+//! `alternate_case` sets a second casing style, used when the enum is
+//! formatted with the `#` flag. The alternate string of a single variant can
+//! be set with `alternate`:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify)]
+//! #[enum_stringify(case = "kebab", alternate_case = "title")]
+//! enum Status {
+//!     InProgress,
+//!     #[enum_stringify(alternate = "All done!")]
+//!     Done,
+//! }
+//!
+//! assert_eq!(format!("{}", Status::InProgress), "in-progress");
+//! assert_eq!(format!("{:#}", Status::InProgress), "In Progress");
+//! assert_eq!(format!("{:#}", Status::Done), "All done!");
+//! ```
+//!
+//! The alternate strings are only used by `Display`, they are not parsed.
+//! Variants with a `rename` or `to` string and no `alternate` are displayed
+//! the same way with and without the `#` flag.
+//!
+//! ## Named Tables
+//!
+//! Additional sets of strings can be defined with `table`, each with its own
+//! prefix, suffix and case. A table named `code` adds the methods `as_code`
+//! and `from_code`, converting the enum to and from its strings:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify, Debug, PartialEq)]
+//! #[enum_stringify(case = "kebab")]
+//! #[enum_stringify(table = "code", case = "upper_flat")]
+//! #[enum_stringify(table = "label", case = "title")]
+//! enum Status {
+//!     InProgress,
+//!     #[enum_stringify(table = "code", rename = "OK")]
+//!     Done,
+//! }
+//!
+//! assert_eq!(Status::InProgress.to_string(), "in-progress");
+//! assert_eq!(Status::InProgress.as_code(), "INPROGRESS");
+//! assert_eq!(Status::InProgress.as_label(), "In Progress");
+//!
+//! assert_eq!(Status::from_code("OK").unwrap(), Status::Done);
+//! assert!(Status::from_label("in-progress").is_err());
+//! ```
+//!
+//! A variant can set its string in a table with `table` and `rename`. Each
+//! table has one string per variant, compared as set by
+//! `parse_case_insensitive`, and skipped, fallback and `other` variants
+//! behave as they do with the main strings. `from_{table}` returns the same
+//! error as `TryFrom<&str>`. Table names must be in snake case, and names
+//! whose methods would clash with the other generated methods, like `str`
+//! giving `as_str`, are rejected.
+//!
+//! ## Borrowing the Name
+//!
+//! `as_str` returns the string written by `Display` without allocating, and
+//! can be used in const contexts:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify)]
+//! #[enum_stringify(case = "lower")]
+//! enum Level {
+//!     Info,
+//!     Warn,
+//! }
+//!
+//! const WARN: &str = Level::Warn.as_str();
+//!
+//! assert_eq!(Level::Info.as_str(), "info");
+//! assert_eq!(WARN, "warn");
+//! ```
+//!
+//! It has the visibility of the enum, and returns an empty string for a
+//! variant skipped from `Display` without a placeholder. It is not generated
+//! when a variant captures unknown strings with `other`, as it has no fixed
+//! string.
+//!
+//! ## Parsing in Const Contexts
+//!
+//! `from_str_const` parses a string in a const context, returning `None`
+//! when it is not recognised:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify, Debug, PartialEq)]
+//! #[enum_stringify(case = "lower")]
+//! enum Level {
+//!     Info,
+//!     #[enum_stringify(alias = "warning")]
+//!     Warn,
+//! }
+//!
+//! const DEFAULT_LEVEL: Level = match Level::from_str_const("info") {
+//!     Some(level) => level,
+//!     None => panic!("invalid default level"),
+//! };
+//!
+//! assert_eq!(DEFAULT_LEVEL, Level::Info);
+//! assert_eq!(Level::from_str_const("warning"), Some(Level::Warn));
+//! assert_eq!(Level::from_str_const("debug"), None);
+//! ```
+//!
+//! It accepts the same strings as `TryFrom<&str>`, but gives `None` for
+//! variants with fields, which can not be built in a const context. It is
+//! not generated with `parse_case_insensitive = "unicode"`.
+//!
+//! ## String Literal Macro
+//!
+//! `literal_macro` generates a macro turning string literals into variants
+//! at compile time, so that a typo is a compile error instead of a parse
+//! error at runtime:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify, Debug, PartialEq)]
+//! #[enum_stringify(case = "lower", literal_macro = "level")]
+//! enum Level {
+//!     Info,
+//!     #[enum_stringify(alias = "warning")]
+//!     Warn,
+//! }
+//!
+//! assert_eq!(level!("info"), Level::Info);
+//! assert_eq!(level!("warning"), Level::Warn);
+//! ```
+//!
+//! ```compile_fail
+//! # use enum_stringify::EnumStringify;
+//! #[derive(EnumStringify)]
+//! #[enum_stringify(case = "lower", literal_macro = "level")]
+//! enum Level {
+//!     Info,
+//! }
+//!
+//! let level = level!("inf"); // unknown Level string "inf", expected one of: info
+//! ```
+//!
+//! The macro accepts the strings parsed into each variant exactly as written,
+//! even when parsing is case-insensitive, and unrecognised literals are never
+//! parsed into a fallback variant. Like any `macro_rules!` macro defined in a
+//! module, it can only be used after the enum, and the enum must be in scope
+//! where it is used.
+//!
+//! ## String Constants
+//!
+//! Each variant gets an associated constant holding its string, named after
+//! the variant in screaming snake case with a `_STR` suffix. They can be used
+//! as patterns to keep hand-written parsers in sync with the derived names:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify)]
+//! #[enum_stringify(case = "kebab")]
+//! enum Command {
+//!     ListFiles,
+//!     Remove,
+//! }
+//!
+//! fn describe(input: &str) -> &'static str {
+//!     match input {
+//!         Command::LIST_FILES_STR => "lists the files",
+//!         Command::REMOVE_STR => "removes a file",
+//!         _ => "unknown command",
+//!     }
+//! }
+//!
+//! assert_eq!(Command::LIST_FILES_STR, "list-files");
+//! assert_eq!(describe("remove"), "removes a file");
+//! ```
+//!
+//! The constants hold the string written by `Display`, and are equal to what
+//! `as_str` returns, an empty string for a variant skipped from `Display`
+//! without a placeholder. A variant capturing unknown strings with `other`
+//! has no fixed string and no constant. Neither do variants whose names give
+//! the same constant name, like `HttpServer` and `HTTPServer`.
+//!
+//! ## Listing Variants
+//!
+//! The unit variants are listed in declaration order by the associated
+//! constants `VARIANTS` and `NAMES`, and can be iterated with `iter`:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify, Debug, PartialEq)]
+//! #[enum_stringify(case = "lower")]
+//! enum Level {
+//!     Info,
+//!     Warn,
+//!     #[enum_stringify(skip)]
+//!     Internal,
+//!     Custom(u8),
+//! }
+//!
+//! assert_eq!(Level::VARIANTS, &[Level::Info, Level::Warn]);
+//! assert_eq!(Level::NAMES, &["info", "warn"]);
+//! assert_eq!(Level::COUNT, 2);
+//! assert_eq!(Level::iter().map(ToString::to_string).collect::<Vec<_>>(), ["info", "warn"]);
+//! ```
+//!
+//! Variants with fields and variants skipped with `skip`, `skip_parse` or
+//! `skip_display` are not listed.
+//!
+//! ## Descriptions
+//!
+//! The doc comments of the variants are returned by `description`, and listed
+//! by `DESCRIPTIONS` in the same order as `VARIANTS`:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify)]
+//! #[enum_stringify(case = "lower")]
+//! enum Level {
+//!     /// Routine information.
+//!     Info,
+//!     /// Something unexpected happened.
+//!     ///
+//!     /// The program keeps running.
+//!     Warn,
+//!     Debug,
+//! }
+//!
+//! assert_eq!(Level::Info.description(), "Routine information.");
+//! assert_eq!(
+//!     Level::Warn.description(),
+//!     "Something unexpected happened.\n\nThe program keeps running."
+//! );
+//! assert_eq!(Level::Debug.description(), "");
+//!
+//! for (name, description) in Level::NAMES.iter().zip(Level::DESCRIPTIONS) {
+//!     println!("{name:8} {description}");
+//! }
+//! ```
+//!
+//! Each line of the comments is trimmed, and variants without comments have
+//! an empty description. When no variant has a doc comment, neither
+//! `description` nor `DESCRIPTIONS` is generated, so that the enum can define
+//! its own `description`.
+//!
+//! ## Metadata
+//!
+//! Values can be attached to variants with `meta(key = value)`. They are
+//! returned as strings by `get_meta`, and each key gets a `const` accessor
+//! named after it, typed after its values:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify)]
+//! enum Fruit {
+//!     #[enum_stringify(meta(color = "red", weight = 3))]
+//!     Apple,
+//!     #[enum_stringify(meta(color = "yellow", sour = true))]
+//!     Lemon,
+//! }
+//!
+//! assert_eq!(Fruit::Apple.get_meta("color"), Some("red"));
+//! assert_eq!(Fruit::Apple.get_meta("weight"), Some("3"));
+//! assert_eq!(Fruit::Lemon.get_meta("weight"), None);
+//!
+//! assert_eq!(Fruit::Apple.weight(), Some(3));
+//! assert_eq!(Fruit::Lemon.sour(), Some(true));
+//! assert_eq!(Fruit::Apple.sour(), None);
+//! ```
+//!
+//! Values can be strings, numbers, booleans or characters. Strings give
+//! `&'static str` accessors, numbers the type of their suffix, `i64` or `f64`
+//! without one. A key must have values of the same type on every variant.
+//! Keys name methods, so they can not be keywords, the name of another
+//! generated item such as `description`, `group` or `as_{table}`, nor a
+//! method of the common derives such as `clone` or `eq`.
+//!
+//! ## Groups
+//!
+//! Variants can be sorted into groups with `group = "..."`, to classify them
+//! next to their strings:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify, Debug, PartialEq)]
+//! #[enum_stringify(case = "snake")]
+//! enum Status {
+//!     #[enum_stringify(group = "client_error")]
+//!     NotFound,
+//!     #[enum_stringify(group = "server_error")]
+//!     Unavailable,
+//!     #[enum_stringify(group = "server_error")]
+//!     Timeout,
+//!     Unknown,
+//! }
+//!
+//! assert_eq!(Status::NotFound.group(), Some("client_error"));
+//! assert_eq!(Status::Unknown.group(), None);
+//! assert!(Status::Timeout.in_group("server_error"));
+//!
+//! assert_eq!(Status::GROUPS, &["client_error", "server_error"]);
+//! assert_eq!(Status::group_variants("server_error"), &[Status::Unavailable, Status::Timeout]);
+//! assert_eq!(Status::group_names("server_error"), &["unavailable", "timeout"]);
+//!
+//! assert_eq!(Status::from_str_in_group("timeout", "server_error"), Ok(Status::Timeout));
+//! assert!(Status::from_str_in_group("not_found", "server_error").is_err());
+//! ```
+//!
+//! A variant belongs to at most one group. `group_variants` and `group_names`
+//! list the variants of a group as `VARIANTS` does, and return empty slices
+//! for unknown groups. `from_str_in_group` parses like `TryFrom<&str>`, and
+//! fails if the variant is not in the group, with the strings of the group as
+//! the expected ones.
+//!
+//! ## Error Handling
+//!
+//! When conversion from a string fails, the error is a `<Enum>ParseError`
+//! type generated next to the enum, with the same visibility. It implements
+//! [`std::error::Error`] and keeps a descriptive message as its `Display`:
+//!
+//! ```
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify, Debug, PartialEq)]
+//! #[enum_stringify(case = "lower")]
+//! enum Numbers {
+//!     One,
+//!     Two,
+//! }
+//!
+//! let error: NumbersParseError = Numbers::try_from("Three").unwrap_err();
+//! assert_eq!(error.to_string(), "Failed to parse string 'Three' for enum Numbers");
+//!
+//! assert_eq!(error.enum_name(), "Numbers");
+//! assert_eq!(error.input(), "Three");
+//! assert_eq!(error.expected(), ["one", "two"]);
+//! ```
+//!
+//! The errors of the parsing traits own the rejected input, so that they can
+//! be propagated with `?` into `Box<dyn Error>` or `anyhow::Error`. The input
+//! is only copied when parsing fails, and `TryFrom<String>` moves the string
+//! into the error instead. `parse_borrowed` parses like `TryFrom<&str>` with
+//! an error borrowing the input, so that a failed conversion does not
+//! allocate, and `into_owned` converts such an error into an owned one.
+//!
+//! `expected` lists the accepted strings in the order the variants are
+//! declared, each variant's main string before its aliases.
+//!
+//! ## Compile Errors
+//!
+//! Misuses of the attributes are reported as compile errors pointing at the
+//! offending tokens, with a suggestion for misspelled keys and cases:
+//!
+//! ```compile_fail
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify)]
+//! #[enum_stringify(prefx = "Pre")] // unknown enum_stringify attribute `prefx`, did you mean `prefix`?
+//! enum Numbers {
+//!     One,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify)]
+//! #[enum_stringify(case = "snak")] // invalid case `snak`, did you mean `snake`?
+//! enum Numbers {
+//!     One,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! use enum_stringify::EnumStringify;
+//!
+//! #[derive(EnumStringify)]
+//! enum Numbers {
+//!     #[enum_stringify(rename = 1)] // expected string literal
+//!     One,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! use enum_stringify::EnumStringify;
 //!
-//! assert_eq!(Status::try_from("okay").unwrap(), Status::Okk);
-//! assert_eq!(Status::try_from("PRE ERROR 3 POST").unwrap(), Status::Error3);
+//! #[derive(EnumStringify)]
+//! struct Numbers; // EnumStringify only works with enums
 //! ```
 //!
-//! ## Error Handling
-//!
-//! When conversion from a string fails, the error type is `String`, containing a descriptive message:
+//! Two variants converted to the same string are rejected, as parsing it
+//! would be ambiguous:
 //!
-//! ```
+//! ```compile_fail
 //! use enum_stringify::EnumStringify;
 //!
-//! #[derive(EnumStringify, Debug, PartialEq)]
-//! #[enum_stringify(case = "lower")]
+//! #[derive(EnumStringify)]
+//! #[enum_stringify(case = "flat")]
 //! enum Numbers {
-//!     One,
-//!     Two,
+//!     FooBar,
+//!     Foobar, // variants `FooBar` and `Foobar` are both converted to and from the string "foobar"
 //! }
-//!
-//! let result = Numbers::try_from("Three");
-//! assert!(result.is_err());
-//! assert_eq!(result.unwrap_err(), "Failed to parse string 'Three' for enum Numbers");
 //! ```
 //!
 //! ## Generated Implementations
@@ -164,146 +890,1104 @@
 //! The macro generates the following trait implementations:
 //!
 //! ```rust, no_run
+//! # use std::borrow::Cow;
 //! enum Numbers { One, Two }
 //!
+//! #[derive(Debug, Clone, PartialEq, Eq)]
+//! struct NumbersParseError<'a> {
+//!     input: Cow<'a, str>,
+//!     expected: &'static [&'static str],
+//! }
+//!
+//! // Accessors for the enum name, input and expected strings, as well as
+//! // `Display` and `std::error::Error` implementations for the error.
+//! # impl<'a> NumbersParseError<'a> {
+//! #     fn new(input: impl Into<Cow<'a, str>>, expected: &'static [&'static str]) -> Self {
+//! #         Self { input: input.into(), expected }
+//! #     }
+//! #     fn into_owned(self) -> NumbersParseError<'static> {
+//! #         NumbersParseError { input: Cow::Owned(self.input.into_owned()), expected: self.expected }
+//! #     }
+//! # }
+//!
+//! impl Numbers {
+//!     const VARIANTS: &'static [Self] = &[Self::One, Self::Two];
+//!     const NAMES: &'static [&'static str] = &["One", "Two"];
+//!     const COUNT: usize = Self::VARIANTS.len();
+//!
+//!     fn iter() -> ::std::slice::Iter<'static, Self> {
+//!         Self::VARIANTS.iter()
+//!     }
+//!
+//!     const fn as_str(&self) -> &'static str {
+//!         match self {
+//!             Self::One => "One",
+//!             Self::Two => "Two",
+//!         }
+//!     }
+//! }
+//!
 //! impl ::std::fmt::Display for Numbers {
 //!     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
 //!         match self {
-//!             Self::One => write!(f, "One"),
-//!             Self::Two => write!(f, "Two"),
+//!             Self::One => f.pad("One"),
+//!             Self::Two => f.pad("Two"),
 //!         }
 //!     }
 //! }
 //!
 //! impl TryFrom<&str> for Numbers {
-//!     type Error = String;
+//!     type Error = NumbersParseError<'static>;
 //!
 //!     fn try_from(s: &str) -> Result<Self, Self::Error> {
 //!         match s {
 //!             "One" => Ok(Self::One),
 //!             "Two" => Ok(Self::Two),
-//!             _ => Err(format!("Invalid value '{}'", s)),
+//!             _ => Err(NumbersParseError::new(s.to_owned(), &["One", "Two"])),
 //!         }
 //!     }
 //! }
 //!
 //! impl TryFrom<String> for Numbers {
-//!     type Error = String;
+//!     type Error = NumbersParseError<'static>;
 //!
 //!     fn try_from(s: String) -> Result<Self, Self::Error> {
-//!         s.as_str().try_into()
+//!         let value = match s.as_str() {
+//!             "One" => Some(Self::One),
+//!             "Two" => Some(Self::Two),
+//!             _ => None,
+//!         };
+//!         match value {
+//!             Some(value) => Ok(value),
+//!             None => Err(NumbersParseError::new(s, &["One", "Two"])),
+//!         }
 //!     }
 //! }
 //!
 //! impl ::std::str::FromStr for Numbers {
-//!     type Err = String;
+//!     type Err = NumbersParseError<'static>;
 //!
 //!     fn from_str(s: &str) -> Result<Self, Self::Err> {
-//!         s.try_into()
+//!         match s {
+//!             "One" => Ok(Self::One),
+//!             "Two" => Ok(Self::Two),
+//!             _ => Err(NumbersParseError::new(s.to_owned(), &["One", "Two"])),
+//!         }
 //!     }
 //! }
 //! ```
+//!
+//! Each parsing trait matches the string on its own, so that any of them can
+//! be left out with `impls(...)`.
 
-use attributes::{Attributes, Variants};
-use proc_macro::TokenStream;
-use quote::quote;
+use attributes::{Attributes, Fallback, Matching, RenamedVariant, Variants};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
 use syn::{parse_macro_input, DeriveInput};
 
 mod attributes;
 mod case;
 
 #[proc_macro_derive(EnumStringify, attributes(enum_stringify))]
-pub fn enum_stringify(input: TokenStream) -> TokenStream {
+pub fn enum_stringify(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     impl_enum_to_string(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
 /// Generates the implementation of `Display`, `FromStr`, `TryFrom<&str>`, and `TryFrom<String>`
 /// for the given enum.
-fn impl_enum_to_string(ast: &syn::DeriveInput) -> TokenStream {
+///
+/// Misuses of the attributes are reported as errors pointing at the
+/// offending tokens.
+fn impl_enum_to_string(ast: &syn::DeriveInput) -> syn::Result<TokenStream> {
     // Extract attributes and variant information from the given AST.
-    let attributes = Attributes::new(ast);
-    let variants = Variants::new(ast);
+    let attributes = Attributes::new(ast)?;
+    let variant_attributes = Variants::new(ast)?;
 
     // Apply rename attributes to the enum variants.
-    // We get the identifier of the variant of the enum and its
-    // new name (based on the attribute information)
-    let pairs = variants.apply(&attributes);
+    // We get each variant of the enum and its new name (based on the
+    // attribute information)
+    let variants = variant_attributes.apply(&attributes)?;
 
-    // Extract the enum name.
+    // Extract the enum name and its generics.
     let name = &ast.ident;
+    let generics = &ast.generics;
 
-    // Unzip for easier handling
-    let identifiers: Vec<&syn::Ident> = pairs.iter().map(|(i, _)| i).collect();
-    let names: Vec<String> = pairs.iter().map(|(_, n)| n.clone()).collect();
+    // Name of the error returned when parsing fails.
+    let error = format_ident!("{}ParseError", name);
 
-    // Generate implementations for each trait
-    let mut gen = impl_display(name, &identifiers, &names);
-    gen.extend(impl_try_from_str(name, &identifiers, &names));
-    gen.extend(impl_try_from_string(name));
-    gen.extend(impl_from_str(name));
-    gen
+    // Expression parsing a string, shared by the parsing traits, and the
+    // strings it accepts.
+    let parse = parse_expression(attributes.matching, &variants);
+    let expected = expected_strings(&variants);
+    let impls = attributes.impls;
+
+    // Generate the error type and the implementations for each trait
+    let mut gen = TokenStream::new();
+    let grouped = variants.iter().any(|variant| variant.group.is_some());
+    if impls.parse_any() || !attributes.tables.is_empty() || grouped {
+        gen.extend(impl_parse_error(name, &ast.vis, &error));
+    }
+    if impls.as_str {
+        let as_str = format_ident!("as_str");
+        let doc = "Returns the string the variant is displayed as.";
+        gen.extend(impl_as_str(
+            name, &ast.vis, generics, &as_str, doc, &variants,
+        ));
+    }
+    // Descriptions are only generated when there is something to describe,
+    // so that enums can keep their own `description` method.
+    let described = impls.description
+        && variants
+            .iter()
+            .any(|variant| !variant.description.is_empty());
+    if described {
+        gen.extend(impl_description(name, &ast.vis, generics, &variants));
+    }
+    if impls.constants {
+        gen.extend(impl_string_constants(name, &ast.vis, generics, &variants));
+    }
+    gen.extend(impl_meta(
+        name,
+        &ast.vis,
+        generics,
+        &variant_attributes.meta_keys,
+        &variants,
+    ));
+    if impls.variants {
+        gen.extend(impl_variant_tables(
+            name, &ast.vis, generics, described, &variants,
+        ));
+    }
+    gen.extend(impl_groups(
+        name, &ast.vis, generics, &error, &parse, &variants,
+    ));
+    if impls.display {
+        gen.extend(impl_display(name, generics, &variants));
+    }
+    if impls.try_from_str {
+        gen.extend(impl_try_from_str(
+            name, &ast.vis, generics, &error, &parse, &expected,
+        ));
+    }
+    if impls.from_str_const {
+        gen.extend(impl_from_str_const(
+            name,
+            &ast.vis,
+            generics,
+            attributes.matching,
+            &variants,
+        ));
+    }
+    if let Some(literal_macro) = &attributes.literal_macro {
+        gen.extend(impl_literal_macro(name, literal_macro, &variants));
+    }
+    if impls.try_from_string {
+        gen.extend(impl_try_from_string(
+            name, generics, &error, &parse, &expected,
+        ));
+    }
+    if impls.from_str {
+        gen.extend(impl_from_str(name, generics, &error, &parse, &expected));
+    }
+    for table in &attributes.tables {
+        let table_variants = variant_attributes.apply_table(&attributes, table)?;
+        gen.extend(impl_table(
+            name,
+            &ast.vis,
+            generics,
+            &error,
+            attributes.matching,
+            &table.name,
+            &table_variants,
+        ));
+    }
+    Ok(gen)
+}
+
+/// Pattern matching a variant, ignoring its fields.
+fn variant_pattern(variant: &RenamedVariant) -> TokenStream {
+    let ident = &variant.ident;
+    match variant.fields {
+        syn::Fields::Unit => quote! { Self::#ident },
+        syn::Fields::Unnamed(_) => quote! { Self::#ident(..) },
+        syn::Fields::Named(_) => quote! { Self::#ident { .. } },
+    }
+}
+
+/// Expression constructing a variant when parsing, if it can be constructed.
+///
+/// Unit variants are always constructible, variants with fields only if they
+/// opted in with `#[enum_stringify(fields = "default")]`.
+fn variant_constructor(variant: &RenamedVariant) -> Option<TokenStream> {
+    variant_constructor_in(&quote! { Self }, variant)
+}
+
+/// Same as [`variant_constructor`], naming the enum through `ty` instead of
+/// `Self`, for code generated outside of its impls.
+fn variant_constructor_in(ty: &TokenStream, variant: &RenamedVariant) -> Option<TokenStream> {
+    let ident = &variant.ident;
+    let default = quote! { ::core::default::Default::default() };
+    match &variant.fields {
+        syn::Fields::Unit => Some(quote! { #ty::#ident }),
+        _ if !variant.default_fields => None,
+        syn::Fields::Unnamed(fields) => {
+            let defaults = fields.unnamed.iter().map(|_| &default);
+            Some(quote! { #ty::#ident(#(#defaults),*) })
+        }
+        syn::Fields::Named(fields) => {
+            let idents = fields.named.iter().map(|field| &field.ident);
+            Some(quote! { #ty::#ident { #(#idents: #default),* } })
+        }
+    }
+}
+
+/// Inherent method returning the string written by `Display`, `as_str` for
+/// the main strings.
+///
+/// Nothing is generated when a variant has no static string, that is when
+/// it captures unknown strings or is skipped from `Display` without a
+/// placeholder.
+fn impl_as_str(
+    name: &syn::Ident,
+    vis: &syn::Visibility,
+    generics: &syn::Generics,
+    method: &syn::Ident,
+    doc: &str,
+    variants: &[RenamedVariant],
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let arms = variants.iter().map(|variant| {
+        let pattern = variant_pattern(variant);
+        let display = (!variant.other).then_some(&variant.display)?;
+        Some(quote! { #pattern => #display })
+    });
+    let Some(arms) = arms.collect::<Option<Vec<_>>>() else {
+        return TokenStream::new();
+    };
+
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc = #doc]
+            #vis const fn #method(&self) -> &'static str {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+    }
+}
+
+/// Inherent `description` returning the doc comments of each variant.
+fn impl_description(
+    name: &syn::Ident,
+    vis: &syn::Visibility,
+    generics: &syn::Generics,
+    variants: &[RenamedVariant],
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let arms = variants.iter().map(|variant| {
+        let pattern = variant_pattern(variant);
+        let description = &variant.description;
+        quote! { #pattern => #description }
+    });
+
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Returns the doc comments of the variant, or an empty string if
+            /// it has none.
+            #vis const fn description(&self) -> &'static str {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+    }
+}
+
+/// Associated constants holding the string of each variant, such as
+/// `VARIANT1_STR` for `Variant1`, so that they can be used as patterns.
+///
+/// The constants are named by `Variants::apply`, which leaves out variants
+/// without a fixed string and variants whose constant names clash.
+fn impl_string_constants(
+    name: &syn::Ident,
+    vis: &syn::Visibility,
+    generics: &syn::Generics,
+    variants: &[RenamedVariant],
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let constants = variants.iter().filter_map(|variant| {
+        let constant = variant.constant.as_ref()?;
+        let display = &variant.display;
+        let ident = &variant.ident;
+        let doc = format!("The string [`{name}::{ident}`] is displayed as.");
+        Some(quote! {
+            #[doc = #doc]
+            #vis const #constant: &'static str = #display;
+        })
+    });
+
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#constants)*
+        }
+    }
+}
+
+/// Inherent `get_meta` returning the values given with `meta(key = value)`
+/// as strings, and a typed accessor named after each key.
+///
+/// Nothing is generated when no variant has any value.
+fn impl_meta(
+    name: &syn::Ident,
+    vis: &syn::Visibility,
+    generics: &syn::Generics,
+    keys: &[(syn::Ident, String)],
+    variants: &[RenamedVariant],
+) -> TokenStream {
+    if keys.is_empty() {
+        return TokenStream::new();
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let arms = variants.iter().flat_map(|variant| {
+        let pattern = variant_pattern(variant);
+        variant.meta.iter().map(move |value| {
+            let key = value.key.to_string();
+            let string = &value.string;
+            quote! { (#pattern, #key) => ::core::option::Option::Some(#string) }
+        })
+    });
+    let accessors = keys.iter().map(|(key, ty)| {
+        let ty: syn::Type = syn::parse_str(ty).expect("meta types are valid types");
+        let doc = format!("Returns the `{key}` meta value of the variant, if it has one.");
+        let arms = variants.iter().filter_map(|variant| {
+            let value = variant.meta.iter().find(|value| value.key == *key)?;
+            let pattern = variant_pattern(variant);
+            let literal = &value.literal;
+            Some(quote! { #pattern => ::core::option::Option::Some(#literal) })
+        });
+        let missing = variants
+            .iter()
+            .any(|variant| variant.meta.iter().all(|value| value.key != *key));
+        let wildcard = missing.then(|| quote! { _ => ::core::option::Option::None, });
+        quote! {
+            #[doc = #doc]
+            #vis const fn #key(&self) -> ::core::option::Option<#ty> {
+                match self {
+                    #(#arms,)*
+                    #wildcard
+                }
+            }
+        }
+    });
+
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Returns the meta value of the variant for `key`, written as a
+            /// string, if it has one.
+            #vis fn get_meta(&self, key: &str) -> ::core::option::Option<&'static str> {
+                match (self, key) {
+                    #(#arms,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #(#accessors)*
+        }
+    }
+}
+
+/// Associated constants listing the unit variants, in declaration order,
+/// and an iterator over them.
+///
+/// Variants with fields and skipped variants are left out. Generic enums only
+/// get the tables when they are `'static`, as the constants are borrowed for
+/// the lifetime of the program. `DESCRIPTIONS` is only generated along with
+/// `description`.
+fn impl_variant_tables(
+    name: &syn::Ident,
+    vis: &syn::Visibility,
+    generics: &syn::Generics,
+    described: bool,
+    variants: &[RenamedVariant],
+) -> TokenStream {
+    let listed: Vec<&RenamedVariant> = variants
+        .iter()
+        .filter(|variant| matches!(variant.fields, syn::Fields::Unit) && !variant.skipped)
+        .collect();
+    let idents = listed.iter().map(|variant| &variant.ident);
+    let names = listed.iter().map(|variant| &variant.display);
+    let descriptions = listed.iter().map(|variant| &variant.description);
+    let descriptions = described.then(|| {
+        quote! {
+            /// The description of each variant in `VARIANTS`, in the same
+            /// order.
+            #vis const DESCRIPTIONS: &'static [&'static str] = &[#(#descriptions),*];
+        }
+    });
+
+    let generics = static_generics(name, generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Every unit variant, in declaration order.
+            #vis const VARIANTS: &'static [Self] = &[#(Self::#idents),*];
+
+            /// The string of each variant in `VARIANTS`, in the same order.
+            #vis const NAMES: &'static [&'static str] = &[#(#names),*];
+
+            #descriptions
+
+            /// The number of variants in `VARIANTS`.
+            #vis const COUNT: usize = Self::VARIANTS.len();
+
+            /// Returns an iterator over `VARIANTS`.
+            #vis fn iter() -> ::std::slice::Iter<'static, Self> {
+                Self::VARIANTS.iter()
+            }
+        }
+    }
+}
+
+/// Adds a `'static` bound on the enum to its generics, for impls with
+/// constants borrowing the enum for the lifetime of the program.
+fn static_generics(name: &syn::Ident, generics: &syn::Generics) -> syn::Generics {
+    let mut generics = generics.clone();
+    if !generics.params.is_empty() {
+        let (_, ty_generics, _) = generics.split_for_impl();
+        let predicate: syn::WherePredicate = syn::parse_quote!(#name #ty_generics: 'static);
+        generics.make_where_clause().predicates.push(predicate);
+    }
+    generics
+}
+
+/// Inherent methods classifying the variants by their `group`, and parsing
+/// the strings of a single group.
+///
+/// Nothing is generated when no variant has a group. The variants listed for
+/// each group are those listed in `VARIANTS`.
+fn impl_groups(
+    name: &syn::Ident,
+    vis: &syn::Visibility,
+    generics: &syn::Generics,
+    error: &syn::Ident,
+    parse: &TokenStream,
+    variants: &[RenamedVariant],
+) -> TokenStream {
+    let mut groups: Vec<&str> = Vec::new();
+    for group in variants
+        .iter()
+        .filter_map(|variant| variant.group.as_deref())
+    {
+        if !groups.contains(&group) {
+            groups.push(group);
+        }
+    }
+    if groups.is_empty() {
+        return TokenStream::new();
+    }
+
+    let group_arms = variants.iter().filter_map(|variant| {
+        let group = variant.group.as_ref()?;
+        let pattern = variant_pattern(variant);
+        Some(quote! { #pattern => ::core::option::Option::Some(#group) })
+    });
+    let wildcard = variants
+        .iter()
+        .any(|variant| variant.group.is_none())
+        .then(|| quote! { _ => ::core::option::Option::None, });
+
+    let members = |group: &str| -> Vec<&RenamedVariant> {
+        variants
+            .iter()
+            .filter(|variant| variant.group.as_deref() == Some(group))
+            .collect()
+    };
+    let listed = |group: &str| -> Vec<&RenamedVariant> {
+        members(group)
+            .into_iter()
+            .filter(|variant| matches!(variant.fields, syn::Fields::Unit) && !variant.skipped)
+            .collect()
+    };
+    let expected_arms = groups.iter().map(|group| {
+        let expected = expected_strings(members(group));
+        quote! { #group => #expected }
+    });
+    let variants_arms = groups.iter().map(|group| {
+        let idents = listed(group).into_iter().map(|variant| &variant.ident);
+        quote! { #group => &[#(Self::#idents),*] }
+    });
+    let names_arms = groups.iter().map(|group| {
+        let names = listed(group).into_iter().map(|variant| &variant.display);
+        quote! { #group => &[#(#names),*] }
+    });
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let static_generics = static_generics(name, generics);
+    let (static_impl_generics, _, static_where_clause) = static_generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Every group of the variants, in the order they first appear.
+            #vis const GROUPS: &'static [&'static str] = &[#(#groups),*];
+
+            /// Returns the group of the variant, if it has one.
+            #vis const fn group(&self) -> ::core::option::Option<&'static str> {
+                match self {
+                    #(#group_arms,)*
+                    #wildcard
+                }
+            }
+
+            /// Returns whether the variant belongs to `group`.
+            #vis fn in_group(&self, group: &str) -> bool {
+                self.group() == ::core::option::Option::Some(group)
+            }
+
+            /// Parses a string into the enum, failing if the variant does not
+            /// belong to `group`.
+            #vis fn from_str_in_group(s: &str, group: &str) -> Result<Self, #error<'static>> {
+                match #parse {
+                    Some(value) if value.in_group(group) => Ok(value),
+                    _ => {
+                        let expected: &'static [&'static str] = match group {
+                            #(#expected_arms,)*
+                            _ => &[],
+                        };
+                        Err(#error::new(s.to_owned(), expected))
+                    }
+                }
+            }
+        }
+
+        impl #static_impl_generics #name #ty_generics #static_where_clause {
+            /// Returns the unit variants of `group`, in declaration order.
+            #vis fn group_variants(group: &str) -> &'static [Self] {
+                match group {
+                    #(#variants_arms,)*
+                    _ => &[],
+                }
+            }
+
+            /// Returns the strings of the variants in `group_variants`, in
+            /// the same order.
+            #vis fn group_names(group: &str) -> &'static [&'static str] {
+                match group {
+                    #(#names_arms,)*
+                    _ => &[],
+                }
+            }
+        }
+    }
 }
 
 /// Implementation of [`std::fmt::Display`].
-fn impl_display(name: &syn::Ident, identifiers: &[&syn::Ident], names: &[String]) -> TokenStream {
+///
+/// Names are written literally with `Formatter::pad`, which honours the
+/// width, fill, alignment and precision of the format string. The alternate
+/// string, if any, is written when the `#` flag is set.
+fn impl_display(
+    name: &syn::Ident,
+    generics: &syn::Generics,
+    variants: &[RenamedVariant],
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let arms = variants.iter().map(|variant| {
+        if variant.other {
+            // The captured string is displayed as is.
+            let ident = &variant.ident;
+            quote! { Self::#ident(value) => ::std::fmt::Display::fmt(value, f) }
+        } else {
+            let display = &variant.display;
+            let pattern = variant_pattern(variant);
+            match &variant.alternate {
+                Some(alternate) => quote! {
+                    #pattern => if f.alternate() {
+                        f.pad(#alternate)
+                    } else {
+                        f.pad(#display)
+                    }
+                },
+                None => quote! { #pattern => f.pad(#display) },
+            }
+        }
+    });
+
     quote! {
-        impl ::std::fmt::Display for #name {
+        impl #impl_generics ::std::fmt::Display for #name #ty_generics #where_clause {
             fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
                 match self {
-                    #(Self::#identifiers => write!(f, #names),)*
+                    #(#arms,)*
+                }
+            }
+        }
+    }
+}
+
+/// Error type returned when parsing a string into the enum fails.
+///
+/// It can borrow the rejected input, so that `parse_borrowed` does not allocate.
+fn impl_parse_error(name: &syn::Ident, vis: &syn::Visibility, error: &syn::Ident) -> TokenStream {
+    let doc = format!("Error returned when a string can not be parsed into [`{name}`].");
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #vis struct #error<'a> {
+            input: ::std::borrow::Cow<'a, str>,
+            expected: &'static [&'static str],
+        }
+
+        impl<'a> #error<'a> {
+            fn new(
+                input: impl Into<::std::borrow::Cow<'a, str>>,
+                expected: &'static [&'static str],
+            ) -> Self {
+                Self {
+                    input: input.into(),
+                    expected,
+                }
+            }
+
+            /// Returns the name of the enum the string was parsed into.
+            pub const fn enum_name(&self) -> &'static str {
+                stringify!(#name)
+            }
+
+            /// Returns the string that could not be parsed.
+            pub fn input(&self) -> &str {
+                &self.input
+            }
+
+            /// Returns the strings that would have been accepted.
+            pub const fn expected(&self) -> &'static [&'static str] {
+                self.expected
+            }
+
+            /// Converts the error into one that owns the rejected input.
+            pub fn into_owned(self) -> #error<'static> {
+                #error {
+                    input: ::std::borrow::Cow::Owned(self.input.into_owned()),
+                    expected: self.expected,
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for #error<'_> {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                write!(
+                    f,
+                    "Failed to parse string '{}' for enum {}",
+                    self.input,
+                    self.enum_name()
+                )
+            }
+        }
+
+        impl ::std::error::Error for #error<'_> {}
+    }
+}
+
+/// Expression parsing the string `s` into `Option<Self>`.
+///
+/// Only variants that can be constructed are parsed. Case-insensitive
+/// matching compares the strings without allocating. Unrecognised strings
+/// are parsed into the fallback variant, or captured by the `other` variant,
+/// if there is one.
+///
+/// Each parsing trait inlines the expression, so that any of them can be
+/// implemented without the others.
+fn parse_expression(matching: Matching, variants: &[RenamedVariant]) -> TokenStream {
+    let (names, constructors): (Vec<Vec<_>>, Vec<_>) = variants
+        .iter()
+        .filter(|variant| !variant.parsed.is_empty())
+        .filter_map(|variant| {
+            let constructor = variant_constructor(variant)?;
+            Some((variant.parse_names().collect(), constructor))
+        })
+        .unzip();
+
+    // What is returned when no name matches.
+    let fallback = variants.iter().find_map(|variant| {
+        let constructor = variant_constructor(variant)?;
+        Some((variant.fallback?, constructor))
+    });
+    let other = variants
+        .iter()
+        .find(|variant| variant.other)
+        .map(|variant| &variant.ident);
+    let unrecognised = match (fallback, other) {
+        (_, Some(other)) => quote! { Some(Self::#other(::core::convert::From::from(s))) },
+        (None, None) => quote! { None },
+        (Some((Fallback::NonEmpty, constructor)), None) => quote! {
+            if s.is_empty() {
+                None
+            } else {
+                Some(#constructor)
+            }
+        },
+        (Some((Fallback::Any, constructor)), None) => quote! { Some(#constructor) },
+    };
+
+    match matching {
+        Matching::Exact => quote! {
+            match s {
+                #(#(#names)|* => Some(#constructors),)*
+                _ => #unrecognised,
+            }
+        },
+        Matching::AsciiCaseInsensitive => quote! {
+            #(if #(s.eq_ignore_ascii_case(#names))||* {
+                Some(#constructors)
+            } else)* {
+                #unrecognised
+            }
+        },
+        Matching::UnicodeCaseInsensitive => {
+            let names: Vec<Vec<_>> = names
+                .iter()
+                .map(|names| names.iter().map(|name| matching.fold(name)).collect())
+                .collect();
+            quote! {
+                #(if #(s.chars().flat_map(char::to_lowercase).eq(#names.chars()))||* {
+                    Some(#constructors)
+                } else)* {
+                    #unrecognised
                 }
             }
         }
     }
-    .into()
 }
 
-/// Implementation of [`TryFrom<&str>`].
+/// The strings accepted by [`parse_expression`], as a slice.
+fn expected_strings<'a>(variants: impl IntoIterator<Item = &'a RenamedVariant>) -> TokenStream {
+    let names = variants
+        .into_iter()
+        .filter(|variant| variant_constructor(variant).is_some())
+        .flat_map(RenamedVariant::parse_names);
+    quote! { &[#(#names),*] }
+}
+
+/// Implementation of [`TryFrom<&str>`], and the inherent `parse_borrowed`
+/// doing the same with an error borrowing the input.
+///
+/// The error of the trait owns the input, so that it can be propagated with
+/// `?` into `Box<dyn Error>` or other `'static` errors. The input is only
+/// copied when parsing fails.
 fn impl_try_from_str(
     name: &syn::Ident,
-    identifiers: &[&syn::Ident],
-    names: &[String],
+    vis: &syn::Visibility,
+    generics: &syn::Generics,
+    error: &syn::Ident,
+    parse: &TokenStream,
+    expected: &TokenStream,
 ) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
     quote! {
-        impl TryFrom<&str> for #name {
-            type Error = String;
+        impl #impl_generics TryFrom<&str> for #name #ty_generics #where_clause {
+            type Error = #error<'static>;
+
+            fn try_from(s: &str) -> Result<Self, #error<'static>> {
+                match #parse {
+                    Some(value) => Ok(value),
+                    None => Err(#error::new(s.to_owned(), #expected)),
+                }
+            }
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Parses a string like `TryFrom<&str>`, with an error borrowing
+            /// the input instead of copying it.
+            #vis fn parse_borrowed(s: &str) -> Result<Self, #error<'_>> {
+                match #parse {
+                    Some(value) => Ok(value),
+                    None => Err(#error::new(s, #expected)),
+                }
+            }
+        }
+    }
+}
+
+/// Inherent `as_{table}` and `from_{table}` methods converting the enum to
+/// and from the strings of a table.
+fn impl_table(
+    name: &syn::Ident,
+    vis: &syn::Visibility,
+    generics: &syn::Generics,
+    error: &syn::Ident,
+    matching: Matching,
+    table: &syn::Ident,
+    variants: &[RenamedVariant],
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let as_table = format_ident!("as_{}", table);
+    let from_table = format_ident!("from_{}", table);
+    let as_doc = format!("Returns the string of the variant in the `{table}` table.");
+    let from_doc = format!("Parses a string of the `{table}` table into the enum.");
+    let parse = parse_expression(matching, variants);
+    let expected = expected_strings(variants);
+
+    let mut gen = impl_as_str(name, vis, generics, &as_table, &as_doc, variants);
+    gen.extend(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc = #from_doc]
+            #vis fn #from_table(s: &str) -> Result<Self, #error<'static>> {
+                match #parse {
+                    Some(value) => Ok(value),
+                    None => Err(#error::new(s.to_owned(), #expected)),
+                }
+            }
+        }
+    });
+    gen
+}
+
+/// Inherent `from_str_const`, parsing like `TryFrom<&str>` in const contexts.
+///
+/// Strings are compared byte by byte as `match` on `&str` is not allowed in
+/// const functions. Variants that can not be built in a const context, those
+/// with fields, give `None` instead. Nothing is generated for Unicode
+/// case-insensitive matching, which can not be done in a const function.
+fn impl_from_str_const(
+    name: &syn::Ident,
+    vis: &syn::Visibility,
+    generics: &syn::Generics,
+    matching: Matching,
+    variants: &[RenamedVariant],
+) -> TokenStream {
+    let compare = match matching {
+        Matching::Exact => quote! { a[i] == b[i] },
+        Matching::AsciiCaseInsensitive => quote! { a[i].eq_ignore_ascii_case(&b[i]) },
+        Matching::UnicodeCaseInsensitive => return TokenStream::new(),
+    };
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // Only unit variants can be built in a const context.
+    let constant = |variant: &RenamedVariant| {
+        if matches!(variant.fields, syn::Fields::Unit) {
+            let ident = &variant.ident;
+            quote! { Some(Self::#ident) }
+        } else {
+            quote! { None }
+        }
+    };
+
+    let (names, constructors): (Vec<Vec<_>>, Vec<_>) = variants
+        .iter()
+        .filter(|variant| !variant.parsed.is_empty() && variant_constructor(variant).is_some())
+        .map(|variant| (variant.parse_names().collect(), constant(variant)))
+        .unzip();
+
+    // What is returned when no name matches.
+    let fallback = variants
+        .iter()
+        .filter(|variant| variant_constructor(variant).is_some())
+        .find_map(|variant| Some((variant.fallback?, constant(variant))));
+    let unrecognised = match fallback {
+        None => quote! { None },
+        Some((Fallback::NonEmpty, constructor)) => quote! {
+            if s.is_empty() {
+                None
+            } else {
+                #constructor
+            }
+        },
+        Some((Fallback::Any, constructor)) => constructor,
+    };
 
-            fn try_from(s: &str) -> Result<Self, Self::Error> {
-                match s {
-                    #(#names => Ok(Self::#identifiers),)*
-                    _ => Err(format!("Failed to parse string '{}' for enum {}", s, stringify!(#name))),
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Parses a string into the enum in a const context, returning
+            /// `None` if it is not recognised.
+            #vis const fn from_str_const(s: &str) -> Option<Self> {
+                const fn eq(a: &[u8], b: &[u8]) -> bool {
+                    if a.len() != b.len() {
+                        return false;
+                    }
+                    let mut i = 0;
+                    while i < a.len() {
+                        if !(#compare) {
+                            return false;
+                        }
+                        i += 1;
+                    }
+                    true
                 }
+
+                let bytes = s.as_bytes();
+                #(if #(eq(bytes, #names.as_bytes()))||* {
+                    return #constructors;
+                })*
+                #unrecognised
             }
         }
     }
-    .into()
+}
+
+/// Macro turning string literals into variants at compile time.
+///
+/// Each string parsed into a variant is matched exactly, any other literal
+/// is a compile error listing the accepted strings. The macro is only
+/// visible after the enum in the same module, and names the enum as it is
+/// declared, so it must be in scope where the macro is used.
+fn impl_literal_macro(
+    name: &syn::Ident,
+    literal_macro: &syn::Ident,
+    variants: &[RenamedVariant],
+) -> TokenStream {
+    let ty = quote! { #name };
+    let (names, constructors): (Vec<Vec<_>>, Vec<_>) = variants
+        .iter()
+        .filter_map(|variant| {
+            let constructor = variant_constructor_in(&ty, variant)?;
+            Some((variant.parse_names().collect(), constructor))
+        })
+        .filter(|(names, _): &(Vec<_>, _)| !names.is_empty())
+        .unzip();
+    let expected = names
+        .iter()
+        .flatten()
+        .copied()
+        .collect::<Vec<_>>()
+        .join(", ");
+    let unknown = format!("unknown {name} string ");
+    let expected = format!(", expected one of: {expected}");
+
+    quote! {
+        #[allow(unused_macros)]
+        macro_rules! #literal_macro {
+            #(#((#names) => { #constructors };)*)*
+            ($other:literal) => {
+                ::core::compile_error!(::core::concat!(#unknown, ::core::stringify!($other), #expected))
+            };
+        }
+    }
 }
 
 /// Implementation of [`TryFrom<String>`].
-fn impl_try_from_string(name: &syn::Ident) -> TokenStream {
+///
+/// The error takes ownership of the rejected string instead of copying it.
+fn impl_try_from_string(
+    name: &syn::Ident,
+    generics: &syn::Generics,
+    error: &syn::Ident,
+    parse: &TokenStream,
+    expected: &TokenStream,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
     quote! {
-        impl TryFrom<String> for #name {
-            type Error = String;
+        impl #impl_generics TryFrom<String> for #name #ty_generics #where_clause {
+            type Error = #error<'static>;
 
-            fn try_from(s: String) -> Result<Self, Self::Error> {
-                s.as_str().try_into()
+            fn try_from(s: String) -> Result<Self, #error<'static>> {
+                let value = {
+                    let s = s.as_str();
+                    #parse
+                };
+                match value {
+                    Some(value) => Ok(value),
+                    None => Err(#error::new(s, #expected)),
+                }
             }
         }
     }
-    .into()
 }
 
 /// Implementation of [`std::str::FromStr`].
-fn impl_from_str(name: &syn::Ident) -> TokenStream {
+fn impl_from_str(
+    name: &syn::Ident,
+    generics: &syn::Generics,
+    error: &syn::Ident,
+    parse: &TokenStream,
+    expected: &TokenStream,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
     quote! {
-        impl ::std::str::FromStr for #name {
-            type Err = String;
+        impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+            type Err = #error<'static>;
+
+            fn from_str(s: &str) -> Result<Self, #error<'static>> {
+                match #parse {
+                    Some(value) => Ok(value),
+                    None => Err(#error::new(s.to_owned(), #expected)),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand(ast: &DeriveInput) -> String {
+        impl_enum_to_string(ast).unwrap().to_string()
+    }
+
+    /// Returns the positions of the given strings in `haystack`.
+    fn positions(haystack: &str, needles: &[&str]) -> Vec<usize> {
+        needles
+            .iter()
+            .map(|needle| haystack.find(needle).unwrap())
+            .collect()
+    }
 
-            fn from_str(s: &str) -> Result<Self, Self::Err> {
-                s.try_into()
+    fn is_sorted(positions: &[usize]) -> bool {
+        positions.windows(2).all(|pair| pair[0] < pair[1])
+    }
+
+    #[test]
+    fn test_expansion_is_reproducible() {
+        let ast: DeriveInput = syn::parse_quote! {
+            #[enum_stringify(case = "lower", parse_case_insensitive)]
+            enum Letters {
+                Zeta,
+                #[enum_stringify(alias = "a", alias = "first")]
+                Alpha,
+                Mu,
+                #[enum_stringify(fallback)]
+                Unknown,
+                Kappa,
+                Beta,
             }
+        };
+        let first = expand(&ast);
+        for _ in 0..16 {
+            assert_eq!(expand(&ast), first);
         }
     }
-    .into()
+
+    #[test]
+    fn test_expansion_follows_declaration_order() {
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Letters {
+                Zeta,
+                #[enum_stringify(alias = "a")]
+                Alpha,
+                Mu,
+            }
+        };
+        let attributes = Attributes::new(&ast).unwrap();
+        let variants = Variants::new(&ast).unwrap().apply(&attributes).unwrap();
+        let names = ["\"Zeta\"", "\"Alpha\"", "\"a\"", "\"Mu\""];
+
+        let display = impl_display(&ast.ident, &ast.generics, &variants).to_string();
+        assert!(is_sorted(&positions(
+            &display,
+            &["\"Zeta\"", "\"Alpha\"", "\"Mu\""]
+        )));
+
+        let parse = parse_expression(attributes.matching, &variants).to_string();
+        assert!(is_sorted(&positions(&parse, &names)));
+
+        let expected = expected_strings(&variants).to_string();
+        assert!(is_sorted(&positions(&expected, &names)));
+    }
 }
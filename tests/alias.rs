@@ -0,0 +1,69 @@
+use std::str::FromStr;
+
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(case = "lower")]
+enum Level {
+    Info,
+    #[enum_stringify(alias = "warning", alias = "w")]
+    Warn,
+    #[enum_stringify(rename = "err", alias = "error")]
+    Error,
+}
+
+#[test]
+fn test_alias_to_string() {
+    assert_eq!(Level::Info.to_string(), "info");
+    assert_eq!(Level::Warn.to_string(), "warn");
+    assert_eq!(Level::Error.to_string(), "err");
+}
+
+#[test]
+fn test_alias_from_str() {
+    assert_eq!(Level::try_from("warn").unwrap(), Level::Warn);
+    assert_eq!(Level::try_from("warning").unwrap(), Level::Warn);
+    assert_eq!(Level::try_from("w".to_string()).unwrap(), Level::Warn);
+    assert_eq!(Level::from_str("err").unwrap(), Level::Error);
+    assert_eq!(Level::from_str("error").unwrap(), Level::Error);
+
+    assert!(Level::try_from("Warning").is_err());
+    assert!(Level::try_from("Error").is_err());
+}
+
+#[test]
+fn test_alias_expected() {
+    let error = Level::try_from("debug").unwrap_err();
+    assert_eq!(
+        error.expected(),
+        ["info", "warn", "warning", "w", "err", "error"]
+    );
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(parse_case_insensitive)]
+enum Answer {
+    #[enum_stringify(alias = "y", alias = "Y")]
+    Yes,
+    #[enum_stringify(alias = "n")]
+    No,
+}
+
+#[test]
+fn test_alias_case_insensitive() {
+    assert_eq!(Answer::try_from("YES").unwrap(), Answer::Yes);
+    assert_eq!(Answer::try_from("y").unwrap(), Answer::Yes);
+    assert_eq!(Answer::try_from("N").unwrap(), Answer::No);
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+enum Shape {
+    #[enum_stringify(fields = "default", alias = "sq")]
+    Square(u32),
+}
+
+#[test]
+fn test_alias_default_fields() {
+    assert_eq!(Shape::try_from("sq").unwrap(), Shape::Square(0));
+    assert_eq!(Shape::Square(2).to_string(), "Square");
+}
@@ -0,0 +1,59 @@
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(case = "kebab", alternate_case = "title")]
+enum Status {
+    InProgress,
+    #[enum_stringify(alternate = "All done")]
+    Done,
+    #[enum_stringify(rename = "n/a")]
+    NotApplicable,
+    #[enum_stringify(to = "blocked!", alternate = "Blocked!")]
+    Blocked,
+    #[enum_stringify(other)]
+    Other(String),
+}
+
+#[test]
+fn test_alternate_case() {
+    assert_eq!(format!("{}", Status::InProgress), "in-progress");
+    assert_eq!(format!("{:#}", Status::InProgress), "In Progress");
+    assert_eq!(format!("{:>#13}|", Status::InProgress), "  In Progress|");
+}
+
+#[test]
+fn test_alternate_variant() {
+    assert_eq!(format!("{}", Status::Done), "done");
+    assert_eq!(format!("{:#}", Status::Done), "All done");
+    assert_eq!(format!("{}", Status::Blocked), "blocked!");
+    assert_eq!(format!("{:#}", Status::Blocked), "Blocked!");
+}
+
+#[test]
+fn test_alternate_explicit_names() {
+    assert_eq!(format!("{:#}", Status::NotApplicable), "n/a");
+    assert_eq!(format!("{:#}", Status::Other("x".to_string())), "x");
+}
+
+#[test]
+fn test_alternate_not_parsed() {
+    assert_eq!(Status::try_from("in-progress").unwrap(), Status::InProgress);
+    assert_eq!(
+        Status::try_from("In Progress").unwrap(),
+        Status::Other("In Progress".to_string())
+    );
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+enum Level {
+    #[enum_stringify(alternate = "information")]
+    Info,
+    Warn,
+}
+
+#[test]
+fn test_alternate_without_case() {
+    assert_eq!(format!("{:#}", Level::Info), "information");
+    assert_eq!(format!("{:#}", Level::Warn), "Warn");
+    assert_eq!(Level::Info.to_string(), "Info");
+}
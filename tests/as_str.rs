@@ -0,0 +1,62 @@
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(case = "lower", prefix = "log.")]
+enum Level {
+    Debug,
+    #[enum_stringify(to = "information")]
+    Info,
+    #[enum_stringify(skip_display = "<hidden>")]
+    Trace,
+    #[enum_stringify(skip_display)]
+    Internal,
+    Data(u8),
+}
+
+const DEBUG: &str = Level::Debug.as_str();
+
+#[test]
+fn test_as_str() {
+    assert_eq!(DEBUG, "log.debug");
+    assert_eq!(Level::Info.as_str(), "information");
+    assert_eq!(Level::Trace.as_str(), "<hidden>");
+    assert_eq!(Level::Internal.as_str(), "");
+    assert_eq!(Level::Data(3).as_str(), "log.data");
+}
+
+#[test]
+fn test_as_str_matches_display() {
+    for level in [
+        Level::Debug,
+        Level::Info,
+        Level::Trace,
+        Level::Internal,
+        Level::Data(0),
+    ] {
+        assert_eq!(level.as_str(), level.to_string());
+    }
+}
+
+#[derive(EnumStringify)]
+enum Wrapper<T> {
+    Value(T),
+    Empty,
+}
+
+#[test]
+fn test_as_str_generics() {
+    assert_eq!(Wrapper::Value(1.5).as_str(), "Value");
+    assert_eq!(Wrapper::<u8>::Empty.as_str(), "Empty");
+}
+
+#[derive(EnumStringify)]
+enum Braces {
+    #[enum_stringify(rename = "{open}")]
+    Open,
+}
+
+#[test]
+fn test_display_braces() {
+    assert_eq!(Braces::Open.to_string(), "{open}");
+    assert_eq!(Braces::Open.as_str(), "{open}");
+}
@@ -0,0 +1,79 @@
+use std::str::FromStr;
+
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(case = "lower", parse_case_insensitive)]
+enum Level {
+    Info,
+    Warn,
+    #[enum_stringify(rename = "Error")]
+    Err,
+}
+
+#[test]
+fn test_display_is_canonical() {
+    assert_eq!(Level::Info.to_string(), "info");
+    assert_eq!(Level::Warn.to_string(), "warn");
+    assert_eq!(Level::Err.to_string(), "Error");
+}
+
+#[test]
+fn test_ascii_case_insensitive() {
+    for input in ["info", "INFO", "Info", "iNfO"] {
+        assert_eq!(Level::try_from(input).unwrap(), Level::Info);
+        assert_eq!(Level::from_str(input).unwrap(), Level::Info);
+        assert_eq!(Level::try_from(input.to_string()).unwrap(), Level::Info);
+    }
+    assert_eq!(Level::try_from("error").unwrap(), Level::Err);
+    assert_eq!(Level::try_from("ERROR").unwrap(), Level::Err);
+
+    assert!(Level::try_from("information").is_err());
+    assert!(Level::try_from(" info").is_err());
+}
+
+#[test]
+fn test_ascii_case_insensitive_error() {
+    let error = Level::try_from("DEBUG").unwrap_err();
+    assert_eq!(error.input(), "DEBUG");
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(parse_case_insensitive = "ascii")]
+enum Accented {
+    Été,
+}
+
+#[test]
+fn test_ascii_ignores_unicode_casing() {
+    assert_eq!(Accented::try_from("Été").unwrap(), Accented::Été);
+    assert_eq!(Accented::try_from("éTÉ").unwrap_err().input(), "éTÉ");
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(parse_case_insensitive = "unicode", case = "upper")]
+enum Season {
+    Été,
+    Hiver,
+}
+
+#[test]
+fn test_unicode_case_insensitive() {
+    assert_eq!(Season::Été.to_string(), "ÉTÉ");
+    assert_eq!(Season::try_from("été").unwrap(), Season::Été);
+    assert_eq!(Season::try_from("ÉtÉ").unwrap(), Season::Été);
+    assert_eq!(Season::from_str("hIVER").unwrap(), Season::Hiver);
+
+    assert!(Season::try_from("ete").is_err());
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+enum Exact {
+    Info,
+}
+
+#[test]
+fn test_case_sensitive_by_default() {
+    assert!(Exact::try_from("info").is_err());
+    assert!(Exact::try_from("INFO").is_err());
+}
@@ -0,0 +1,83 @@
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(prefix = "v.")]
+enum Version {
+    Variant1,
+    HTTPServer,
+    #[enum_stringify(to = "Display", from = "parse")]
+    DarkRed,
+    #[enum_stringify(skip_display = "-")]
+    Hidden,
+    #[enum_stringify(skip_display)]
+    Secret,
+    Data(u8),
+}
+
+#[test]
+fn test_constants() {
+    assert_eq!(Version::VARIANT1_STR, "v.Variant1");
+    assert_eq!(Version::HTTP_SERVER_STR, "v.HTTPServer");
+    assert_eq!(Version::DARK_RED_STR, "Display");
+    assert_eq!(Version::HIDDEN_STR, "-");
+    assert_eq!(Version::SECRET_STR, "");
+    assert_eq!(Version::DATA_STR, "v.Data");
+}
+
+#[test]
+fn test_constants_as_patterns() {
+    let parse = |s: &str| match s {
+        Version::VARIANT1_STR => Some(Version::Variant1),
+        Version::HTTP_SERVER_STR => Some(Version::HTTPServer),
+        _ => None,
+    };
+    assert_eq!(parse("v.Variant1"), Some(Version::Variant1));
+    assert_eq!(parse("v.HTTPServer"), Some(Version::HTTPServer));
+    assert_eq!(parse("v.Data"), None);
+}
+
+#[test]
+fn test_constants_match_display() {
+    assert_eq!(Version::Variant1.to_string(), Version::VARIANT1_STR);
+    assert_eq!(Version::DarkRed.to_string(), Version::DARK_RED_STR);
+    assert_eq!(Version::Data(1).to_string(), Version::DATA_STR);
+    assert_eq!(Version::Hidden.to_string(), Version::HIDDEN_STR);
+    assert!(Version::try_from("v.Secret").is_ok());
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+enum Proto {
+    HttpServer,
+    HTTPServer,
+    Ftp,
+}
+
+#[test]
+fn test_clashing_constants_are_left_out() {
+    assert_eq!(Proto::FTP_STR, "Ftp");
+    assert_eq!(Proto::HttpServer.as_str(), "HttpServer");
+    assert_eq!(Proto::HTTPServer.as_str(), "HTTPServer");
+    assert_eq!(Proto::try_from("HTTPServer").unwrap(), Proto::HTTPServer);
+}
+
+#[allow(non_camel_case_types)]
+#[derive(EnumStringify, Debug, PartialEq)]
+enum Kw {
+    r#type,
+    Other,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(EnumStringify, Debug, PartialEq)]
+enum V {
+    _1,
+    _2,
+}
+
+#[test]
+fn test_constants_of_unusual_identifiers() {
+    assert_eq!(Kw::TYPE_STR, Kw::r#type.as_str());
+    assert_eq!(Kw::OTHER_STR, "Other");
+    assert_eq!(V::_1.to_string(), "_1");
+    assert_eq!(V::try_from("_2").unwrap(), V::_2);
+}
@@ -0,0 +1,74 @@
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(case = "lower")]
+enum Level {
+    /// Routine information.
+    Info,
+    /// Something unexpected happened.
+    ///
+    /// The program keeps running.
+    #[enum_stringify(alias = "warning")]
+    Warn,
+    Debug,
+    /// Not listed.
+    #[enum_stringify(skip)]
+    Internal,
+    /// A custom level.
+    Custom(u8),
+    /// Any other level.
+    #[enum_stringify(other)]
+    Other(String),
+}
+
+const INFO: &str = Level::Info.description();
+
+#[test]
+fn test_description() {
+    assert_eq!(INFO, "Routine information.");
+    assert_eq!(
+        Level::Warn.description(),
+        "Something unexpected happened.\n\nThe program keeps running."
+    );
+    assert_eq!(Level::Debug.description(), "");
+    assert_eq!(Level::Internal.description(), "Not listed.");
+    assert_eq!(Level::Custom(1).description(), "A custom level.");
+    assert_eq!(
+        Level::Other("trace".to_string()).description(),
+        "Any other level."
+    );
+}
+
+#[test]
+fn test_descriptions() {
+    assert_eq!(
+        Level::DESCRIPTIONS,
+        &[
+            "Routine information.",
+            "Something unexpected happened.\n\nThe program keeps running.",
+            "",
+        ]
+    );
+    assert_eq!(Level::DESCRIPTIONS.len(), Level::COUNT);
+    for (variant, description) in Level::iter().zip(Level::DESCRIPTIONS) {
+        assert_eq!(&variant.description(), description);
+    }
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+enum Undocumented {
+    One,
+    Two,
+}
+
+impl Undocumented {
+    fn description(&self) -> String {
+        format!("the number {self}")
+    }
+}
+
+#[test]
+fn test_no_description_without_doc_comments() {
+    assert_eq!(Undocumented::One.description(), "the number One");
+    assert_eq!(Undocumented::NAMES, ["One", "Two"]);
+}
@@ -0,0 +1,111 @@
+use std::error::Error;
+use std::str::FromStr;
+
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(case = "lower")]
+enum Level {
+    Info,
+    #[enum_stringify(rename = "warning")]
+    Warn,
+    Error(String),
+}
+
+#[test]
+fn test_variant_named_error() {
+    assert_eq!(Level::Error("disk full".to_string()).to_string(), "error");
+    assert!(Level::try_from("error").is_err());
+}
+
+#[test]
+fn test_error_accessors() {
+    let error = Level::try_from("Debug").unwrap_err();
+    assert_eq!(error.enum_name(), "Level");
+    assert_eq!(error.input(), "Debug");
+    assert_eq!(error.expected(), ["info", "warning"]);
+}
+
+#[test]
+fn test_error_display() {
+    assert_eq!(
+        Level::try_from("Debug").unwrap_err().to_string(),
+        "Failed to parse string 'Debug' for enum Level"
+    );
+    assert_eq!(
+        Level::try_from("Debug".to_string())
+            .unwrap_err()
+            .to_string(),
+        "Failed to parse string 'Debug' for enum Level"
+    );
+    assert_eq!(
+        Level::from_str("Debug").unwrap_err().to_string(),
+        "Failed to parse string 'Debug' for enum Level"
+    );
+}
+
+#[test]
+fn test_error_into_owned() {
+    let error = {
+        let input = "Debug".to_string();
+        Level::parse_borrowed(input.as_str())
+            .unwrap_err()
+            .into_owned()
+    };
+    assert_eq!(error.input(), "Debug");
+    assert_eq!(error, Level::from_str("Debug").unwrap_err());
+}
+
+fn parse_level(s: &str) -> Result<Level, Box<dyn Error>> {
+    Ok(s.parse::<Level>()?)
+}
+
+#[test]
+fn test_error_borrowed() {
+    let input = "Debug".to_string();
+    let error: LevelParseError<'_> = Level::parse_borrowed(&input).unwrap_err();
+    assert_eq!(error.input(), "Debug");
+    assert_eq!(error, Level::try_from(input.as_str()).unwrap_err());
+    assert_eq!(Level::parse_borrowed("info"), Ok(Level::Info));
+}
+
+fn try_level(s: &str) -> Result<Level, Box<dyn Error>> {
+    Ok(Level::try_from(s)?)
+}
+
+#[test]
+fn test_error_trait() {
+    assert_eq!(parse_level("info").unwrap(), Level::Info);
+
+    let error = parse_level("Debug").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Failed to parse string 'Debug' for enum Level"
+    );
+    assert!(error.downcast_ref::<LevelParseError>().is_some());
+}
+
+#[test]
+fn test_error_propagation_from_borrowed_input() {
+    let input = String::from("Debug");
+    let error = try_level(&input).unwrap_err();
+    drop(input);
+    assert_eq!(
+        error.to_string(),
+        "Failed to parse string 'Debug' for enum Level"
+    );
+    assert_eq!(try_level("warning").unwrap(), Level::Warn);
+}
+
+mod visibility {
+    #[derive(enum_stringify::EnumStringify, Debug)]
+    pub enum Public {
+        A,
+    }
+}
+
+#[test]
+fn test_error_visibility() {
+    let error: visibility::PublicParseError = visibility::Public::try_from("B").unwrap_err();
+    assert_eq!(error.input(), "B");
+}
@@ -0,0 +1,62 @@
+use std::str::FromStr;
+
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(case = "lower")]
+enum Color {
+    Red,
+    Green,
+    #[enum_stringify(fallback)]
+    Unknown,
+}
+
+#[test]
+fn test_fallback_known() {
+    assert_eq!(Color::try_from("red").unwrap(), Color::Red);
+    assert_eq!(Color::try_from("green").unwrap(), Color::Green);
+    assert_eq!(Color::try_from("unknown").unwrap(), Color::Unknown);
+    assert_eq!(Color::Unknown.to_string(), "unknown");
+}
+
+#[test]
+fn test_fallback_unknown() {
+    assert_eq!(Color::try_from("purple").unwrap(), Color::Unknown);
+    assert_eq!(Color::try_from("RED".to_string()).unwrap(), Color::Unknown);
+    assert_eq!(Color::from_str(" ").unwrap(), Color::Unknown);
+}
+
+#[test]
+fn test_fallback_rejects_empty() {
+    assert!(Color::try_from("").is_err());
+    assert!(Color::try_from(String::new()).is_err());
+    assert!(Color::from_str("").is_err());
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(parse_case_insensitive)]
+enum Feed {
+    Article,
+    #[enum_stringify(fallback(empty))]
+    Other,
+}
+
+#[test]
+fn test_fallback_empty() {
+    assert_eq!(Feed::try_from("ARTICLE").unwrap(), Feed::Article);
+    assert_eq!(Feed::try_from("video").unwrap(), Feed::Other);
+    assert_eq!(Feed::try_from("").unwrap(), Feed::Other);
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+enum Code {
+    Ok,
+    #[enum_stringify(fallback, fields = "default")]
+    Unknown(u16),
+}
+
+#[test]
+fn test_fallback_default_fields() {
+    assert_eq!(Code::try_from("Ok").unwrap(), Code::Ok);
+    assert_eq!(Code::try_from("Teapot").unwrap(), Code::Unknown(0));
+}
@@ -0,0 +1,77 @@
+use std::str::FromStr;
+
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+enum Event {
+    Started,
+    Moved(i32, i32),
+    Resized { width: u32, height: u32 },
+    Stopped,
+}
+
+#[test]
+fn test_fields_to_string() {
+    assert_eq!(Event::Started.to_string(), "Started");
+    assert_eq!(Event::Moved(1, 2).to_string(), "Moved");
+    assert_eq!(
+        Event::Resized {
+            width: 1,
+            height: 2
+        }
+        .to_string(),
+        "Resized"
+    );
+    assert_eq!(Event::Stopped.to_string(), "Stopped");
+}
+
+#[test]
+fn test_fields_are_not_parsed() {
+    assert_eq!(Event::try_from("Started").unwrap(), Event::Started);
+    assert_eq!(Event::from_str("Stopped").unwrap(), Event::Stopped);
+
+    assert!(Event::try_from("Moved").is_err());
+    assert!(Event::try_from("Resized".to_string()).is_err());
+    assert!(Event::from_str("Resized").is_err());
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(case = "snake")]
+enum DefaultEvent {
+    #[enum_stringify(fields = "default")]
+    MovedTo(i32, i32),
+    #[enum_stringify(fields = "default")]
+    ResizedTo { width: u32, height: u32 },
+    #[enum_stringify(rename = "named")]
+    Named(String),
+}
+
+#[test]
+fn test_default_fields_to_string() {
+    assert_eq!(DefaultEvent::MovedTo(1, 2).to_string(), "moved_to");
+    assert_eq!(
+        DefaultEvent::ResizedTo {
+            width: 1,
+            height: 2
+        }
+        .to_string(),
+        "resized_to"
+    );
+    assert_eq!(DefaultEvent::Named("a".to_string()).to_string(), "named");
+}
+
+#[test]
+fn test_default_fields_from_str() {
+    assert_eq!(
+        DefaultEvent::try_from("moved_to").unwrap(),
+        DefaultEvent::MovedTo(0, 0)
+    );
+    assert_eq!(
+        DefaultEvent::from_str("resized_to").unwrap(),
+        DefaultEvent::ResizedTo {
+            width: 0,
+            height: 0
+        }
+    );
+    assert!(DefaultEvent::try_from("named").is_err());
+}
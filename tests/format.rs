@@ -0,0 +1,41 @@
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(case = "lower")]
+enum Level {
+    Info,
+    Warn,
+    #[enum_stringify(rename = "{id}")]
+    Placeholder,
+    #[enum_stringify(rename = "}{")]
+    Braces,
+    #[enum_stringify(other)]
+    Other(String),
+}
+
+#[test]
+fn test_literal_names() {
+    assert_eq!(Level::Placeholder.to_string(), "{id}");
+    assert_eq!(Level::Braces.to_string(), "}{");
+    assert_eq!(Level::try_from("{id}").unwrap(), Level::Placeholder);
+}
+
+#[test]
+fn test_width_and_alignment() {
+    assert_eq!(format!("{:>6}|", Level::Info), "  info|");
+    assert_eq!(format!("{:<6}|", Level::Warn), "warn  |");
+    assert_eq!(format!("{:^8}|", Level::Info), "  info  |");
+    assert_eq!(format!("{:*>6}", Level::Info), "**info");
+    assert_eq!(format!("{:2}", Level::Info), "info");
+}
+
+#[test]
+fn test_precision() {
+    assert_eq!(format!("{:.2}", Level::Warn), "wa");
+    assert_eq!(format!("{:5.1}|", Level::Info), "i    |");
+}
+
+#[test]
+fn test_other_formatting() {
+    assert_eq!(format!("{:>6}", Level::Other("err".to_string())), "   err");
+}
@@ -0,0 +1,72 @@
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(case = "lower")]
+enum Level {
+    Info,
+    #[enum_stringify(alias = "warning")]
+    Warn,
+    #[enum_stringify(skip_parse)]
+    Internal,
+    #[enum_stringify(fields = "default")]
+    Custom(u8),
+}
+
+const DEFAULT_LEVEL: Level = match Level::from_str_const("info") {
+    Some(level) => level,
+    None => panic!("invalid default level"),
+};
+
+#[test]
+fn test_from_str_const() {
+    assert_eq!(DEFAULT_LEVEL, Level::Info);
+    assert_eq!(Level::from_str_const("warn"), Some(Level::Warn));
+    assert_eq!(Level::from_str_const("warning"), Some(Level::Warn));
+
+    assert_eq!(Level::from_str_const("Info"), None);
+    assert_eq!(Level::from_str_const("inf"), None);
+    assert_eq!(Level::from_str_const(""), None);
+    assert_eq!(Level::from_str_const("internal"), None);
+}
+
+#[test]
+fn test_from_str_const_fields() {
+    assert_eq!(Level::try_from("custom").unwrap(), Level::Custom(0));
+    assert_eq!(Level::from_str_const("custom"), None);
+    assert_eq!(Level::Internal.to_string(), "internal");
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(parse_case_insensitive)]
+enum Answer {
+    Yes,
+    No,
+    #[enum_stringify(fallback)]
+    Maybe,
+}
+
+#[test]
+fn test_from_str_const_case_insensitive_fallback() {
+    const YES: Option<Answer> = Answer::from_str_const("YES");
+    assert_eq!(YES, Some(Answer::Yes));
+    assert_eq!(Answer::from_str_const("nO"), Some(Answer::No));
+    assert_eq!(Answer::from_str_const("perhaps"), Some(Answer::Maybe));
+    assert_eq!(Answer::from_str_const(""), None);
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+enum Token {
+    Word,
+    #[enum_stringify(other)]
+    Other(String),
+}
+
+#[test]
+fn test_from_str_const_other() {
+    assert_eq!(Token::from_str_const("Word"), Some(Token::Word));
+    assert_eq!(Token::from_str_const("anything"), None);
+    assert_eq!(
+        Token::try_from("anything").unwrap(),
+        Token::Other("anything".to_string())
+    );
+}
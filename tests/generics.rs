@@ -0,0 +1,83 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+enum Event<'a, T> {
+    #[enum_stringify(group = "lifecycle")]
+    Started,
+    #[enum_stringify(rename = "msg")]
+    Message(&'a str),
+    Payload {
+        value: T,
+    },
+}
+
+#[test]
+fn test_generic_to_string() {
+    assert_eq!(Event::<u8>::Started.to_string(), "Started");
+    assert_eq!(Event::<u8>::Message("hello").to_string(), "msg");
+    assert_eq!(Event::Payload { value: 42 }.to_string(), "Payload");
+}
+
+#[test]
+fn test_generic_groups() {
+    assert_eq!(Event::<u8>::Started.group(), Some("lifecycle"));
+    assert_eq!(Event::<u8>::Message("hello").group(), None);
+    assert_eq!(Event::<u8>::group_variants("lifecycle"), &[Event::Started]);
+}
+
+#[test]
+fn test_generic_from_str() {
+    assert_eq!(Event::<u8>::try_from("Started").unwrap(), Event::Started);
+    assert_eq!(
+        Event::<u8>::try_from("Started".to_string()).unwrap(),
+        Event::Started
+    );
+    assert_eq!(Event::<u8>::from_str("Started").unwrap(), Event::Started);
+
+    assert!(Event::<u8>::try_from("msg").is_err());
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(case = "lower")]
+enum Bounded<T: Debug, U>
+where
+    U: Default,
+{
+    #[enum_stringify(fields = "default")]
+    Value(PhantomData<T>, U),
+    Empty,
+}
+
+#[test]
+fn test_bounds_and_where_clause() {
+    assert_eq!(
+        Bounded::<u8, u8>::Value(PhantomData, 1).to_string(),
+        "value"
+    );
+    assert_eq!(Bounded::<u8, u8>::Empty.to_string(), "empty");
+
+    assert_eq!(
+        Bounded::<u8, u8>::try_from("value").unwrap(),
+        Bounded::Value(PhantomData, 0)
+    );
+    assert_eq!(
+        Bounded::<u8, String>::from_str("empty").unwrap(),
+        Bounded::Empty
+    );
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+enum Buffer<const N: usize> {
+    Array([u8; N]),
+    Nothing,
+}
+
+#[test]
+fn test_const_generics() {
+    assert_eq!(Buffer::Array([1, 2]).to_string(), "Array");
+    assert_eq!(Buffer::<3>::try_from("Nothing").unwrap(), Buffer::Nothing);
+}
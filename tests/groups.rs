@@ -0,0 +1,94 @@
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(case = "snake")]
+enum Status {
+    #[enum_stringify(group = "success")]
+    Ok,
+    #[enum_stringify(group = "client_error")]
+    NotFound,
+    #[enum_stringify(group = "client_error", alias = "rate_limited")]
+    TooManyRequests,
+    #[enum_stringify(group = "server_error")]
+    Unavailable,
+    #[enum_stringify(group = "client_error", skip)]
+    Internal,
+    Unknown,
+}
+
+const OK_GROUP: Option<&str> = Status::Ok.group();
+
+#[test]
+fn test_group() {
+    assert_eq!(OK_GROUP, Some("success"));
+    assert_eq!(Status::NotFound.group(), Some("client_error"));
+    assert_eq!(Status::Internal.group(), Some("client_error"));
+    assert_eq!(Status::Unknown.group(), None);
+    assert_eq!(Status::GROUPS, &["success", "client_error", "server_error"]);
+}
+
+#[test]
+fn test_in_group() {
+    assert!(Status::NotFound.in_group("client_error"));
+    assert!(!Status::NotFound.in_group("server_error"));
+    assert!(!Status::Unknown.in_group(""));
+    let retryable: Vec<&Status> = Status::iter()
+        .filter(|status| status.in_group("server_error"))
+        .collect();
+    assert_eq!(retryable, [&Status::Unavailable]);
+}
+
+#[test]
+fn test_group_tables() {
+    assert_eq!(
+        Status::group_variants("client_error"),
+        &[Status::NotFound, Status::TooManyRequests]
+    );
+    assert_eq!(
+        Status::group_names("client_error"),
+        &["not_found", "too_many_requests"]
+    );
+    assert_eq!(Status::group_variants("success"), &[Status::Ok]);
+    assert!(Status::group_variants("unknown").is_empty());
+    assert!(Status::group_names("unknown").is_empty());
+}
+
+#[test]
+fn test_from_str_in_group() {
+    assert_eq!(
+        Status::from_str_in_group("not_found", "client_error"),
+        Ok(Status::NotFound)
+    );
+    assert_eq!(
+        Status::from_str_in_group("rate_limited", "client_error"),
+        Ok(Status::TooManyRequests)
+    );
+
+    let error = Status::from_str_in_group("unavailable", "client_error").unwrap_err();
+    assert_eq!(error.input(), "unavailable");
+    assert_eq!(
+        error.expected(),
+        ["not_found", "too_many_requests", "rate_limited"]
+    );
+
+    let error = Status::from_str_in_group("ok", "unknown").unwrap_err();
+    assert!(error.expected().is_empty());
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(impls(display))]
+enum Signal {
+    #[enum_stringify(group = "fatal")]
+    Kill,
+    #[enum_stringify(group = "fatal")]
+    Abort,
+}
+
+#[test]
+fn test_groups_without_parsing_impls() {
+    assert_eq!(Signal::Kill.group(), Some("fatal"));
+    assert_eq!(
+        Signal::from_str_in_group("Abort", "fatal"),
+        Ok(Signal::Abort)
+    );
+}
@@ -0,0 +1,99 @@
+use std::fmt;
+use std::str::FromStr;
+
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(case = "lower", impls(from_str, try_from_str, try_from_string, as_str))]
+enum Level {
+    Info,
+    Warn,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "level {}", self.as_str())
+    }
+}
+
+#[test]
+fn test_custom_display() {
+    assert_eq!(Level::Info.to_string(), "level info");
+    assert_eq!(Level::try_from("warn").unwrap(), Level::Warn);
+    assert_eq!(Level::try_from("info".to_string()).unwrap(), Level::Info);
+    assert_eq!(Level::from_str("warn").unwrap(), Level::Warn);
+    assert!(Level::from_str("level info").is_err());
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(impls(display, from_str), parse_case_insensitive)]
+enum Answer {
+    Yes,
+    No,
+    #[enum_stringify(fallback)]
+    Unknown,
+}
+
+impl TryFrom<&str> for Answer {
+    type Error = ();
+
+    fn try_from(s: &str) -> Result<Self, ()> {
+        match s {
+            "y" => Ok(Self::Yes),
+            "n" => Ok(Self::No),
+            _ => Err(()),
+        }
+    }
+}
+
+#[test]
+fn test_custom_try_from() {
+    assert_eq!(Answer::Yes.to_string(), "Yes");
+    assert_eq!(Answer::from_str("NO").unwrap(), Answer::No);
+    assert_eq!(Answer::from_str("maybe").unwrap(), Answer::Unknown);
+    assert!(Answer::from_str("").is_err());
+    assert_eq!(Answer::try_from("y"), Ok(Answer::Yes));
+    assert_eq!(Answer::try_from("Yes"), Err(()));
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(impls(try_from_string))]
+enum Token {
+    Word,
+    #[enum_stringify(other)]
+    Other(String),
+}
+
+#[test]
+fn test_only_try_from_string() {
+    assert_eq!(Token::try_from("Word".to_string()).unwrap(), Token::Word);
+    assert_eq!(
+        Token::try_from("text".to_string()).unwrap(),
+        Token::Other("text".to_string())
+    );
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(impls(display))]
+enum Shape {
+    Circle,
+    Square,
+}
+
+impl Shape {
+    const VARIANTS: [Self; 2] = [Self::Circle, Self::Square];
+
+    fn as_str(&self) -> String {
+        format!("shape {self}")
+    }
+
+    fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.into_iter()
+    }
+}
+
+#[test]
+fn test_inherent_items_left_out() {
+    assert_eq!(Shape::Circle.as_str(), "shape Circle");
+    assert_eq!(Shape::iter().last(), Some(Shape::Square));
+}
@@ -0,0 +1,55 @@
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(case = "lower", literal_macro = "level")]
+enum Level {
+    Info,
+    #[enum_stringify(alias = "warning", alias = "w")]
+    Warn,
+    #[enum_stringify(skip_parse)]
+    Internal,
+    #[enum_stringify(fields = "default")]
+    Custom(u8),
+    Data {
+        value: u8,
+    },
+}
+
+#[test]
+fn test_literal_macro() {
+    assert_eq!(level!("info"), Level::Info);
+    assert_eq!(level!("warn"), Level::Warn);
+    assert_eq!(level!("warning"), Level::Warn);
+    assert_eq!(level!("w"), Level::Warn);
+    assert_eq!(level!("custom"), Level::Custom(0));
+}
+
+#[test]
+fn test_literal_macro_const() {
+    const DEFAULT: Level = level!("info");
+    assert_eq!(DEFAULT, Level::Info);
+}
+
+#[test]
+fn test_literal_macro_unlisted() {
+    assert_eq!(Level::Internal.to_string(), "internal");
+    assert_eq!(Level::Data { value: 1 }.to_string(), "data");
+}
+
+mod nested {
+    use enum_stringify::EnumStringify;
+
+    #[derive(EnumStringify, Debug, PartialEq)]
+    #[enum_stringify(literal_macro = "maybe", parse_case_insensitive)]
+    pub enum Maybe<T> {
+        Nothing,
+        Just(T),
+    }
+
+    #[test]
+    fn test_literal_macro_generics() {
+        let nothing: Maybe<u8> = maybe!("Nothing");
+        assert_eq!(nothing, Maybe::Nothing);
+        assert_eq!(Maybe::Just(1).to_string(), "Just");
+    }
+}
@@ -0,0 +1,54 @@
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(case = "lower")]
+enum Fruit {
+    #[enum_stringify(meta(color = "red", weight = 3, sour = false))]
+    Apple,
+    #[enum_stringify(meta(color = "yellow", ratio = 0.5f32, grade = 'b'))]
+    Lemon,
+    Plain,
+    #[enum_stringify(meta(color = "mixed"))]
+    Basket(u8),
+}
+
+const APPLE_WEIGHT: Option<i64> = Fruit::Apple.weight();
+
+#[test]
+fn test_get_meta() {
+    assert_eq!(Fruit::Apple.get_meta("color"), Some("red"));
+    assert_eq!(Fruit::Apple.get_meta("weight"), Some("3"));
+    assert_eq!(Fruit::Apple.get_meta("sour"), Some("false"));
+    assert_eq!(Fruit::Lemon.get_meta("ratio"), Some("0.5"));
+    assert_eq!(Fruit::Lemon.get_meta("grade"), Some("b"));
+    assert_eq!(Fruit::Lemon.get_meta("weight"), None);
+    assert_eq!(Fruit::Plain.get_meta("color"), None);
+    assert_eq!(Fruit::Basket(2).get_meta("color"), Some("mixed"));
+    assert_eq!(Fruit::Apple.get_meta("unknown"), None);
+}
+
+#[test]
+fn test_typed_meta() {
+    assert_eq!(APPLE_WEIGHT, Some(3));
+    assert_eq!(Fruit::Apple.color(), Some("red"));
+    assert_eq!(Fruit::Basket(2).color(), Some("mixed"));
+    assert_eq!(Fruit::Plain.color(), None);
+    assert_eq!(Fruit::Apple.sour(), Some(false));
+    assert_eq!(Fruit::Lemon.ratio(), Some(0.5f32));
+    assert_eq!(Fruit::Lemon.grade(), Some('b'));
+    assert_eq!(Fruit::Lemon.weight(), None);
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+enum Shape {
+    #[enum_stringify(meta(sides = 3u8))]
+    Triangle,
+    #[enum_stringify(meta(sides = 4u8))]
+    Square,
+}
+
+#[test]
+fn test_typed_meta_on_every_variant() {
+    let sides: Vec<Option<u8>> = Shape::iter().map(Shape::sides).collect();
+    assert_eq!(sides, [Some(3), Some(4)]);
+}
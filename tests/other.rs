@@ -0,0 +1,61 @@
+use std::str::FromStr;
+
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(case = "lower")]
+enum Mime {
+    Json,
+    Html,
+    #[enum_stringify(other)]
+    Other(String),
+}
+
+#[test]
+fn test_other_known() {
+    assert_eq!(Mime::try_from("json").unwrap(), Mime::Json);
+    assert_eq!(Mime::from_str("html").unwrap(), Mime::Html);
+    assert_eq!(Mime::Json.to_string(), "json");
+}
+
+#[test]
+fn test_other_captures() {
+    assert_eq!(
+        Mime::try_from("text/csv").unwrap(),
+        Mime::Other("text/csv".to_string())
+    );
+    assert_eq!(
+        Mime::try_from("JSON".to_string()).unwrap(),
+        Mime::Other("JSON".to_string())
+    );
+    assert_eq!(Mime::from_str("").unwrap(), Mime::Other(String::new()));
+    assert_eq!(
+        Mime::try_from("other").unwrap(),
+        Mime::Other("other".to_string())
+    );
+}
+
+#[test]
+fn test_other_round_trip() {
+    for input in ["json", "html", "image/png", "Json", ""] {
+        assert_eq!(Mime::try_from(input).unwrap().to_string(), input);
+    }
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(parse_case_insensitive)]
+enum Vendor {
+    Acme,
+    #[enum_stringify(other)]
+    Unknown(Box<str>),
+}
+
+#[test]
+fn test_other_case_insensitive() {
+    assert_eq!(Vendor::try_from("ACME").unwrap(), Vendor::Acme);
+    assert_eq!(
+        Vendor::try_from("Initech").unwrap(),
+        Vendor::Unknown("Initech".into())
+    );
+    assert_eq!(Vendor::Unknown("Initech".into()).to_string(), "Initech");
+}
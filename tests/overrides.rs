@@ -0,0 +1,50 @@
+use std::str::FromStr;
+
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(prefix = "log.", suffix = "!", case = "lower")]
+enum Level {
+    Info,
+    #[enum_stringify(case = "upper")]
+    Warn,
+    #[enum_stringify(no_prefix, alias = "fatal")]
+    Error,
+    #[enum_stringify(prefix = "dbg.", no_suffix)]
+    Debug,
+    #[enum_stringify(suffix = "?")]
+    Trace,
+}
+
+#[test]
+fn test_overrides_to_string() {
+    assert_eq!(Level::Info.to_string(), "log.info!");
+    assert_eq!(Level::Warn.to_string(), "LOG.WARN!");
+    assert_eq!(Level::Error.to_string(), "error!");
+    assert_eq!(Level::Debug.to_string(), "dbg.debug");
+    assert_eq!(Level::Trace.to_string(), "log.trace?");
+}
+
+#[test]
+fn test_overrides_from_str() {
+    assert_eq!(Level::from_str("LOG.WARN!"), Ok(Level::Warn));
+    assert_eq!(Level::from_str("error!"), Ok(Level::Error));
+    assert_eq!(Level::from_str("fatal"), Ok(Level::Error));
+    assert_eq!(Level::from_str("dbg.debug"), Ok(Level::Debug));
+    assert!(Level::from_str("log.warn!").is_err());
+}
+
+#[test]
+fn test_overrides_tables() {
+    assert_eq!(Level::WARN_STR, "LOG.WARN!");
+    assert_eq!(
+        Level::NAMES,
+        &[
+            "log.info!",
+            "LOG.WARN!",
+            "error!",
+            "dbg.debug",
+            "log.trace?"
+        ]
+    );
+}
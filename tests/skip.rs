@@ -0,0 +1,66 @@
+use std::str::FromStr;
+
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(case = "lower")]
+enum State {
+    Running,
+    #[enum_stringify(skip_parse)]
+    Starting,
+    #[enum_stringify(skip_display)]
+    Hidden,
+    #[enum_stringify(skip_display = "?")]
+    Unknown,
+    #[enum_stringify(skip)]
+    Sentinel,
+    #[enum_stringify(skip = "<internal>")]
+    Internal,
+}
+
+#[test]
+fn test_skip_parse() {
+    assert_eq!(State::Starting.to_string(), "starting");
+    assert!(State::try_from("starting").is_err());
+    assert!(State::from_str("starting").is_err());
+}
+
+#[test]
+fn test_skip_display() {
+    assert_eq!(State::Hidden.to_string(), "");
+    assert_eq!(format!("[{:>3}]", State::Hidden), "[   ]");
+    assert_eq!(State::try_from("hidden").unwrap(), State::Hidden);
+
+    assert_eq!(State::Unknown.to_string(), "?");
+    assert_eq!(State::try_from("unknown").unwrap(), State::Unknown);
+    assert!(State::try_from("?").is_err());
+}
+
+#[test]
+fn test_skip() {
+    assert_eq!(State::Sentinel.to_string(), "");
+    assert!(State::try_from("sentinel").is_err());
+
+    assert_eq!(State::Internal.to_string(), "<internal>");
+    assert!(State::try_from("internal").is_err());
+    assert!(State::try_from("<internal>").is_err());
+}
+
+#[test]
+fn test_skip_expected() {
+    let error = State::try_from("stopped").unwrap_err();
+    assert_eq!(error.expected(), ["running", "hidden", "unknown"]);
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+enum Shadowed {
+    Name,
+    #[enum_stringify(skip_parse, rename = "Name")]
+    Legacy,
+}
+
+#[test]
+fn test_skip_parse_no_collision() {
+    assert_eq!(Shadowed::Legacy.to_string(), "Name");
+    assert_eq!(Shadowed::try_from("Name").unwrap(), Shadowed::Name);
+}
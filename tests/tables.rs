@@ -0,0 +1,82 @@
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(case = "kebab")]
+#[enum_stringify(table = "code", case = "upper_flat")]
+#[enum_stringify(table = "label", case = "title")]
+enum Status {
+    InProgress,
+    #[enum_stringify(table = "code", rename = "OK")]
+    Done,
+    #[enum_stringify(skip_parse)]
+    Unknown,
+}
+
+#[test]
+fn test_as_table() {
+    assert_eq!(Status::InProgress.as_str(), "in-progress");
+    assert_eq!(Status::InProgress.as_code(), "INPROGRESS");
+    assert_eq!(Status::InProgress.as_label(), "In Progress");
+    assert_eq!(Status::Done.as_code(), "OK");
+    assert_eq!(Status::Done.as_label(), "Done");
+    assert_eq!(Status::Unknown.as_code(), "UNKNOWN");
+}
+
+#[test]
+fn test_from_table() {
+    assert_eq!(Status::from_code("INPROGRESS").unwrap(), Status::InProgress);
+    assert_eq!(Status::from_code("OK").unwrap(), Status::Done);
+    assert_eq!(Status::from_label("Done").unwrap(), Status::Done);
+    assert!(Status::from_code("DONE").is_err());
+    assert!(Status::from_code("UNKNOWN").is_err());
+    assert!(Status::from_label("in-progress").is_err());
+}
+
+#[test]
+fn test_from_table_error() {
+    let error = Status::from_code("NOPE").unwrap_err();
+    assert_eq!(error.input(), "NOPE");
+    assert_eq!(error.expected(), ["INPROGRESS", "OK"]);
+    assert_eq!(error.enum_name(), "Status");
+
+    let error = Status::try_from("nope").unwrap_err();
+    assert_eq!(error.expected(), ["in-progress", "done"]);
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(impls(display), parse_case_insensitive)]
+#[enum_stringify(table = "short", case = "lower")]
+enum Answer {
+    #[enum_stringify(table = "short", rename = "y")]
+    Yes,
+    #[enum_stringify(table = "short", rename = "n")]
+    No,
+    #[enum_stringify(fallback)]
+    Unknown,
+}
+
+#[test]
+fn test_table_matching_and_fallback() {
+    assert_eq!(Answer::Yes.to_string(), "Yes");
+    assert_eq!(Answer::from_short("Y").unwrap(), Answer::Yes);
+    assert_eq!(Answer::from_short("n").unwrap(), Answer::No);
+    assert_eq!(Answer::from_short("maybe").unwrap(), Answer::Unknown);
+    assert!(Answer::from_short("").is_err());
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(table = "short")]
+enum Token {
+    Word,
+    #[enum_stringify(other)]
+    Other(String),
+}
+
+#[test]
+fn test_table_other() {
+    assert_eq!(
+        Token::from_short("x").unwrap(),
+        Token::Other("x".to_string())
+    );
+    assert_eq!(Token::Word.to_string(), "Word");
+}
@@ -0,0 +1,48 @@
+use std::str::FromStr;
+
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(case = "lower")]
+enum Method {
+    #[enum_stringify(to = "GET", from = "get", from = "Get")]
+    Get,
+    #[enum_stringify(to = "POST")]
+    Post,
+    #[enum_stringify(from = "remove", alias = "rm")]
+    Delete,
+    Head,
+}
+
+#[test]
+fn test_to() {
+    assert_eq!(Method::Get.to_string(), "GET");
+    assert_eq!(Method::Post.to_string(), "POST");
+    assert_eq!(Method::Delete.to_string(), "delete");
+    assert_eq!(Method::Head.to_string(), "head");
+}
+
+#[test]
+fn test_from() {
+    assert_eq!(Method::try_from("get").unwrap(), Method::Get);
+    assert_eq!(Method::try_from("Get").unwrap(), Method::Get);
+    assert!(Method::try_from("GET").is_err());
+
+    assert_eq!(Method::from_str("post").unwrap(), Method::Post);
+    assert!(Method::from_str("POST").is_err());
+
+    assert_eq!(Method::try_from("remove").unwrap(), Method::Delete);
+    assert_eq!(Method::try_from("rm".to_string()).unwrap(), Method::Delete);
+    assert!(Method::try_from("delete").is_err());
+
+    assert_eq!(Method::try_from("head").unwrap(), Method::Head);
+}
+
+#[test]
+fn test_to_from_expected() {
+    let error = Method::try_from("patch").unwrap_err();
+    assert_eq!(
+        error.expected(),
+        ["get", "Get", "post", "remove", "rm", "head"]
+    );
+}
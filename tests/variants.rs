@@ -0,0 +1,62 @@
+use enum_stringify::EnumStringify;
+
+#[derive(EnumStringify, Debug, PartialEq)]
+#[enum_stringify(case = "kebab")]
+enum Color {
+    DarkRed,
+    Green,
+    #[enum_stringify(rename = "sky")]
+    LightBlue,
+    #[enum_stringify(skip_parse)]
+    Unset,
+    Rgb(u8, u8, u8),
+    Black,
+}
+
+#[test]
+fn test_variants() {
+    assert_eq!(
+        Color::VARIANTS,
+        &[Color::DarkRed, Color::Green, Color::LightBlue, Color::Black]
+    );
+}
+
+#[test]
+fn test_names() {
+    assert_eq!(Color::NAMES, &["dark-red", "green", "sky", "black"]);
+    for (variant, name) in Color::VARIANTS.iter().zip(Color::NAMES) {
+        assert_eq!(&variant.to_string(), name);
+        assert_eq!(&Color::try_from(*name).unwrap(), variant);
+    }
+}
+
+#[test]
+fn test_count() {
+    const COUNT: usize = Color::COUNT;
+    assert_eq!(COUNT, 4);
+}
+
+#[test]
+fn test_iter() {
+    let names: Vec<String> = Color::iter().map(ToString::to_string).collect();
+    assert_eq!(names, Color::NAMES);
+}
+
+#[test]
+fn test_unlisted() {
+    assert_eq!(Color::Unset.to_string(), "unset");
+    assert_eq!(Color::Rgb(0, 0, 0).to_string(), "rgb");
+}
+
+#[derive(EnumStringify, Debug, PartialEq)]
+enum Maybe<T> {
+    Nothing,
+    Just(T),
+}
+
+#[test]
+fn test_generics() {
+    assert_eq!(Maybe::<u8>::VARIANTS, &[Maybe::Nothing]);
+    assert_eq!(Maybe::<String>::NAMES, &["Nothing"]);
+    assert_eq!(Maybe::Just(1).to_string(), "Just");
+}